@@ -1,4 +1,4 @@
-pub const CAMERA: [&'static str; 4] = [
+pub const CAMERA: [&str; 4] = [
     r"                                
            ____            
        _[]/____\__n__     
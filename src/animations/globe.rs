@@ -1,693 +1,271 @@
-// credit: https://web.archive.org/web/20000918113202/http://www.ludd.luth.se/~vk/pics/ascii/junkyard/creative/animations/globe_animation.html
+use crate::frame::style::Style;
+use crate::frame::text::Text;
+use crate::geo::{canvas_string, canvas_text, is_land, place_marker, Canvas, Marker};
+use std::f32::consts::PI;
 
-pub const GLOBE: [&'static str; 30] = [
-    " 
-                   _-o#&&*''''?d:>b\\_
-               _o/\"`''  '',, dMF9MMMMMHo_
-            .o&#'        `\"MbHMMMMMMMMMMMHo.
-          .o\"\" '         vodM*$&&HMMMMMMMMMM?.
-         ,'              $M&ood,~'`(&##MMMMMMH\\
-        /               ,MMMMMMM#b?#bobMMMMHMMML
-       &              ?MMMMMMMMMMMMMMMMM7MMM$R*Hk
-      ?$.            :MMMMMMMMMMMMMMMMMMM/HMMM|`*L
-     |               |MMMMMMMMMMMMMMMMMMMMbMH'   T,
-     $H#:            `*MMMMMMMMMMMMMMMMMMMMb#}'  `?
-     ]MMH#             \"\"*\"\"\"\"*#MMMMMMMMMMMMM'    -
-     MMMMMb_                   |MMMMMMMMMMMP'     :
-     HMMMMMMMHo                 `MMMMMMMMMT       .
-     ?MMMMMMMMP                  9MMMMMMMM}       -
-     -?MMMMMMM                  |MMMMMMMMM?,d-    '
-      :|MMMMMM-                 `MMMMMMMT .M|.   :
-       .9MMM[                    &MMMMM*' `'    .
-        :9MMk                    `MMM#\"        -
-          &M}                     `          .-
-           `&.                             .
-             `~,   .                     ./
-                 . _                  .-",
-    " 
-                   _v->#H#P? \"':o<>\\_
-               .,dP` `''  \"'-o.+H6&MMMHo_
-             oHMH9'         `?&bHMHMMMMMMHo.
-           oMP\"' '           ooMP*#&HMMMMMMM?.
-         ,M*          -     `*MSdob//`^&##MMMH\\
-        d*'                .,MMMMMMH#o>#ooMMMMMb
-       HM-                :HMMMMMMMMMMMMMMM&HM[R\\
-      d\"Z\\.               9MMMMMMMMMMMMMMMMM[HMM|:
-     -H    -              MMMMMMMMMMMMMMMMMMMbMP' :
-     :??Mb#               `9MMMMMMMMMMMMMMMMMMH#! .
-     : MMMMH#,              \"*\"\"\"\"`#HMMMMMMMMMMH  -
-     ||MMMMMM6\\.                    {MMMMMMMMMH'  :
-     :|MMMMMMMMMMHo                 `9MMMMMMMM'   .
-     . HMMMMMMMMMMP'                 !MMMMMMMM    `
-     - `#MMMMMMMMM                   HMMMMMMM*,/  :
-      :  ?MMMMMMMF                   HMMMMMM',P' :
-       .  HMMMMR'                    {MMMMP' ^' -
-        : `HMMMT                     iMMH'     .'
-         -.`HMH                               .
-           -:*H                            . '
-             -`\\,,    .                  .-
-               ' .  _                 .-`",
-    " 
-                   .ovr:HMM#?:`' >b\\_
-               .,:&Hi' `'   \"' \\\\|&bSMHo_
-             oHMMM#*}          `?&dMMMMMMHo.
-          .dMMMH\"''''           ,oHH*&&9MMMM?.
-         ,MMM*'                 `*M\\bd<|\"*&#MH\\
-        dHH?'                   :MMMMMM#bd#odMML
-       H' |\\                  `dMMMMMMMMMMMMMM9Mk
-      JL/\"7+,.                `MMMMMMMMMMMMMMMH9ML
-     -`Hp     '               |MMMMMMMMMMMMMMMMHH|:
-     :  \\\\#M#d?                `HMMMMMMMMMMMMMMMMH.
-     .   JMMMMM##,              ``*\"\"'\"*#MMMMMMMMH
-     -. ,MMMMMMMM6o_                    |MMMMMMMM':
-     :  |MMMMMMMMMMMMMb\\                 TMMMMMMT :
-     .   ?MMMMMMMMMMMMM'                 :MMMMMM|.`
-     -    ?HMMMMMMMMMM:                  HMMMMMM\\|:
-      :     9MMMMMMMMH'                 `MMMMMP.P.
-       .    `MMMMMMT''                   HMMM*''-
-        -    TMMMMM'                     MM*'  -
-         '.   HMM#                            -
-           -. `9M:                          .'
-             -. `b,,    .                . '
-               '-\\   .,               .-`",
-    " 
-                   _oo##'9MMHb':'-,o_
-               .oH\":HH$' \"\"'  \"' -\\7*R&o_
-            .oHMMMHMH#9:          \"\\bMMMMHo.
-           dMMMMMM*\"\"'`'           .oHM\"H9MM?.
-         ,MMMMMM'                   \"HLbd<|?&H\\
-        JMMH#H'                     |MMMMM#b>bHb
-       :MH  .\"\\                   `|MMMMMMMMMMMM&
-      .:M:d-\"|:b..                 9MMMMMMMMMMMMM+
-     :  \"*H|      -                &MMMMMMMMMMMMMH:
-     .    `LvdHH#d?                `?MMMMMMMMMMMMMb
-     :      iMMMMMMH#b               `\"*\"'\"#HMMMMMM
-     .   . ,MMMMMMMMMMb\\.                   {MMMMMH
-     -     |MMMMMMMMMMMMMMHb,               `MMMMM|
-     :      |MMMMMMMMMMMMMMH'                &MMMM,
-     -       `#MMMMMMMMMMMM                 |MMMM6-
-      :        `MMMMMMMMMM+                 ]MMMT/
-       .       `MMMMMMMP\"                   HMM*`
-        -       |MMMMMH'                   ,M#'-
-         '.     :MMMH|                       .-
-           .     |MM                        -
-            ` .   `#?..    .             ..'
-                -.     _.             .-",
-    " 
-                   _ooppH[`MMMD::--\\_
-               _oHMR\":&M&. \"\"'  \"'  /&\\\\_
-             oHMMMMMHMMH#9,         `\"<MMHo.
-           oHMMMMMMMM*\"\"'``           .dMP#M?.
-         .dMMMMMMMM*                   `H\\do?&\\
-        -iMMMHH#H'                      &MMMHb#?
-       : ZMM'   7-.                   `{MMMMMMMMH
-      .  .M6_d|\"`$|v..                 9MMMMMMMMML
-     -    `'*H#       :                |MMMMMMMMMM:
-     :        *\\\\dHM#dd.                ?MMMMMMMMMb
-     -          |MMMMMMM##\\              `\"*\"\"?HMMM
-     :      .  |MMMMMMMMMMMo\\.                 {MMM
-     .         {MMMMMMMMMMMMMMMHo.             `MMM
-     -          ?MMMMMMMMMMMMMMM*'             -MMP
-     :           `#MMMMMMMMMMMMT               dMM'
-      -            |MMMMMMMMMMH'              -MMT
-       :           `MMMMMMMM\"'                JMP
-        -           MMMMMMH'                 ,H?
-         '.         HMMM#'                    :
-           .        ?MM-                   . '
-             -.      *M:..               .-
-                - .      _.           .-",
-    " 
-                   .oo>#&H6?*MMH:-.._
-               .oHMMMR:\"&MZ\\ `\"'  \"  |$-_
-            ..dMMMMMMMMdMMM#9\\        `'HHo.
-          . ,dMMMMMMMMMMM\"`' `           ?MP?.
-         . |MMMMMMMMMMM'                 `\"$b&\\
-        -  |MMMMHH##M'                     HMMH?
-       -   TTMM|    >..                   \\MMMMMH
-      :     |MM\\,#-\"\"$~b\\.                `MMMMMM+
-     .       ``\"H&#        -               &MMMMMM|
-     :            *\\v,#MHddc.              `9MMMMMb
-     .               MMMMMMMM##\\             `\"\":HM
-     -          .  .HMMMMMMMMMMRo_.              |M
-     :             |MMMMMMMMMMMMMMMM#\\           :M
-     -              `HMMMMMMMMMMMMMMM'           |T
-     :               `*HMMMMMMMMMMMM'            H'
-      :                 MMMMMMMMMMM|            |T
-       .                MMMMMMMM?'             ./
-       `.               MMMMMMH'              ./
-         -.            |MMMH#'                .
-           .           `MM*                . '
-             -.         #M: .    .       .-
-               ` .         .,         .-",
-    " 
-                   _ood>H&H&Z?#M#b-\\.
-               .\\HMMMMMR?`\\M6b.\"`' ''``v.
-            .. .MMMMMMMMMMHMMM#&.      ``~o.
-          .   ,HMMMMMMMMMMMM*\"'-`          &b.
-         .   .MMMMMMMMMMMMH'               `\"&\\
-        -     RMMMMM#H##R'                   4Mb
-       -      |7MMM'    ?::                 `|MMb
-      /         HMM__#|`\"\\>?v..              `MMML
-     .           `\"'#Hd|       `              9MMM:
-     -                |\\,\\?HH#bbL             `9MMb
-     :                   !MMMMMMMH#b,          `\"\"T
-     .              .   ,MMMMMMMMMMMbo.           |
-     :                  4MMMMMMMMMMMMMMMHo        |
-     :                   ?MMMMMMMMMMMMMMM?        :
-     -.                   `#MMMMMMMMMMMM:        .-
-      :                     |MMMMMMMMMM?         .
-       -                    JMMMMMMMT'          :
-       `.                   MMMMMMH'           -
-         -.                |MMM#*`            -
-           .               HMH'            . '
-             -.            #H:.          .-
-               ` .           .\\       .-",
-    " 
-                   _,\\?dZkMHF&$*q#b..
-               .//9MMMMMMM?:'HM\\\\\"`-''`..
-            ..`  :MMMMMMMMMMHMMMMH?_    `-\\
-          .     .dMMMMMMMMMMMMMM'\"'\"       `\\.
-         .      |MMMMMMMMMMMMMR              \\\\
-        -        T9MMMMMHH##M\"                `?
-       :          (9MMM'    !':.               &k
-      .:            HMM\\_?p \"\":-b\\.            `ML
-     -                \"'\"H&#,       :           |M|
-     :                     ?\\,\\dMH#b#.           9b
-     :                        |MMMMMMM##,        `*
-     :                   .   +MMMMMMMMMMMo_       -
-     :                       HMMMMMMMMMMMMMM#,    :
-     :                        9MMMMMMMMMMMMMH'    .
-     : .                       *HMMMMMMMMMMP     .'
-      :                          MMMMMMMMMH'     .
-       -                        :MMMMMMM'`      .
-       `.                       9MMMMM*'       -
-         -.                    {MMM#'         :
-           -                  |MM\"          .'
-            `.                &M'..  .   ..'
-               ' .             ._     .-",
-    " 
-                   _oo:\\bk99M[<$$+b\\.
-                .$*\"MMMMMMMM[:\"\\Mb\\?^\" .
-            . '`    HMMMMMMMMMMHMMMM+?.  `.
-          .        .HMMMMMMMMMMMMMMP\"''     .
-         .         `MMMMMMMMMMMMMM|         -`.
-        -           `&MMMMMMHH##H:             :
-       :             `(*MMM}    `|\\             |
-      : `-              ?MMb__#|\"\"`|+v..         \\
-     .                    `''*H#b       -        :|
-     :                         `*\\v,#M#b#,        \\
-     .                             9MMMMMMHb.     :
-     :                        .   #MMMMMMMMMb\\    -
-     -                           .HMMMMMMMMMMMMb  :
-     :                            `MMMMMMMMMMMMH  .
-     -:  .                         `#MMMMMMMMMP   '
-      :                              ]MMMMMMMH'  :
-       -                            ,MMMMMM?'   .
-       `:                           HMMMMH\"    -
-         -.                       .HMM#*     .-
-          `.                     .HH*'     .
-            `-.                  &R\".    .-
-                -.               ._   .-",
-    " 
-                   _\\oo\\?ddk9MRbS>v\\_
-               ..:>*\"\"MMMMMMMMM:?|H?$?-.
-            ..- -     \"HMMMMMMMMMMHMMMH\\_-.
-          .            dMMMMMMMMMMMMMMT\"    .
-         .             TMMMMMMMMMMMMMM       `.
-        -               `&HMMMMMM#H#H:         .
-       -                 `\\7HMMH     |\\.        .
-      :    `                 HMM\\_?c`\"\"+?\\..     :
-     -                         \"``#&#|      .     -
-     :                              `?,\\#MHdb.    .
-     :                                 |MMMMMH#.  :
-     :                            .   ,HMMMMMMMb, -
-     : '                              4MMMMMMMMMMH`
-     :   .                             9MMMMMMMMMT-
-     :.`                               `#MMMMMMMH '
-      :      '                           HMMMMMH':
-       -                                |MMMMH\" -
-       `:                              |MMMH*' .'
-         '?                           dMM#'   .
-           \\.                       .dH\"    .'
-             -.                    ,M'-  ..'
-               ` .                .. ..-`",
-    " 
-                   _o,:o?\\?dM&MHcc~,.
-               ..^':&#\"\"HMMMMMMMM$:?&&?.
-             .`  -`      'HMMMMMMMMMHMMMp\\.
-          . '             |MMMMMMMMMMMMMM\"' .
-         .                `9MMMMMMMMMMMMM    -.
-        -                   `*9MMMMMHH##[      .
-       -                     `\\Z9MMM    `~\\     .
-      :       '|                 ?MMb_?p\"\"-?v..  :
-     -                             `\"'*&#,    -   .
-     :                                  `?,oHH#?  .
-     --                                    |MMMMH,:
-     :                                 .  |MMMMMM6,
-     :   -                                |MMMMMMMM
-     ?                                     HMMMMMMP
-     -- . '                                |HMMMMM'
-      :.`     .  '                          JMMMM+
-       \\                                   ,MMMP:
-        :                                 |MMH?:
-         -:\\.                            dM#\" .
-            \\                          ,H*' .'
-             -.                       d':..'
-               ` .                  .,.-",
-    " 
-                   _o\\:,??\\??MR9#cb\\_
-               .v/''':&#\"\"#HMMMMMMM$?*d\\.
-            ..~' - -`      `\"#MMMMMMMMMMMHv.
-          .-'                 HMMMMMMMMMMMR!.
-         :                    `9MMMMMMMMMMM| -.
-        .                       `*9MMMMMH##|   .
-       -                          `(#MMH   `:,  .
-      :           '|                 `HMb_>/\"|\\,.:
-     .'                                `\"'#&b   - .
-     :                                      ?\\oHH?.
-     :                                        !MMM&
-     :  .                                  .  HMMMM
-     /.      -                               -MMMMM
-     \\`.                                      9MMMP
-     :. .  . -                                |MMM'
-      \\... '                                  .MMT
-       &.                                    .dMP
-        \\,                                  .HM*
-         \\. `\\.                            ,H&'
-          `- `| -                        ,&':
-            `.                         ,/\\ '
-               '-..                  _.-",
-    " 
-                   _,d?,:?o?:?HM>#b\\_
-               ..H*\"''`'H#*\"**MMMMMM6$$v_
-             v//\"   - ``      `'#MMMMMMMMHo.
-           /\"`                   |MMMMMMMMMM:.
-         ,>                       `HMMMMMMMMH:.
-        :                           `#HMMMMHH\\ -
-       '                              `Z#MM,  `,:
-      :               '\\                 ?HH_>:`\\,
-     :                                     \"'*&| `:
-     .                                         <\\Hb
-     :                                           MM
-     :                                        . iMM
-     Mb\\.                                       {MM
-     ::.`-       -                              !MP
-     `&.   .  .  -                              :M'
-      9H,  \\  '                                 |T
-       HM?                                     ,P
-        *ML                                   ??
-         :&.   `o                           .d'
-           ':  |T                          /\"
-             -.                         .<''
-               `...                  ..-",
-    " 
-                   _,oc>?_:b?o?HH#b\\_
-               .v/99*\"\"\" '*H#\"\"*HMMMMMZ,_
-             oH* /\"   -   '      \"`#MMMMM#o.
-          ./*>-                     `MMMMMMMb
-         ,b/'                        `#MMMMMMM\\
-        :'                             ``HMMMMb:
-       /-                                `|&MH `\\
-      /                   `-.               |Hb??\\
-     ,-  '                                    \"`&,.
-     1                                           \\}
-     !.                                           T
-     $,.                                        . 1
-     ?`M??.                                       M
-     ?.::| '\\        -                            ?
-      M?&.    .   .  -                           ,'
-      9MMH\\   ..  '           `                  .
-       HMMM#.                                   :'
-        9#MMb                                 ..
-         -:\"#     `b.                        .-
-           . `    {!                        /
-             -                           ,-'
-               ' .                    .-",
-    " 
-                   _\\o##??,:io??$#b\\_
-               .oH#\"H9*\"\"\" \"`#H*\"*#MMMHo_
-             oHMM- -'    -  ''     ``*HMMHo.
-           dM#S>-`                     ?MMMM?.
-         ,&&,/'                         \"#MMMH\\
-        d?-\"                              `*HMMb
-       H?                                   \"ZHb:
-      /:                        \\              H?L
-     |:|   .                                    `*:
-     :?:                                          \\
-     >\"                                           :
-     M|\\,_                                        |
-     !|\":HH?-'.                                   :
-     :^'_:?\"\\ `--         -                       .
-     - |ML?b      .   ..  -                       -
-      :HMMMMH\\    \\               `              :
-       >MMMMMM#.                                .
-        ^M*HMMM|                               -
-         `. `\"#+     `?v                     .`
-           .   `-    +?'                    -
-            ` .                          ..'
-                - .                   .-",
-    " 
-                   _,o#bH\\??::?o?cbo_
-               .o#MH#**SH\"\"' \"`*H#\"*#MHo_
-             oHMMMH^  ^\"    -  `      '*HHo.
-          .dMMM#\">>-                     `HM?.
-         ,MH:R_o/                         `*MH\\
-        dMM' '                               \"ML
-       HMR! '                                 `#k
-      d&'.                          -.          `L
-     :M ::     `                                 `-
-     /| !|                                        -
-     k.$-\"                                        :
-     }9R:!,,_.                                    .
-     \\::\\':`*M#\\-'.                               -
-     : \"''..:\"!`\\  '-          -                  `
-     -   ,HMb.H|      .    _   -                 .'
-      : ,MMMMMMMb.    ..                         .
-       .`HMMMMMMMM?                             .
-       `.`9M#*HMMMM                            :
-         -.'   \"##*      `b,                  .
-           .      `     ,/'                 .'
-            ` .                          ..'
-                - .                  ..-",
-    " 
-                   _,o#&oHb?\\o::d?>\\_
-               .oHHMMM#**$M\"\"` \"`*HH\"#&o_
-             oHMMMMMMD' .''    -  '    ``bo.
-          .dMMMMMH*'/|-                   `\\b.
-         ,MMMM?T|_o/                        `\\\\
-        dMMMMP  ''                            `|
-       HMMMH& -                                `\\
-      /MH7' :                          --        :
-     -:MM  {.      .                              .
-     :i?' .!&                                     .
-     :{, o| '                                     :
-     -T?9M\\:-'o,_                                 .
-     : \\?::``\"`?9MHo./..                          -
-     .  '\"`'^ _.`\"!\"^.  `-         -              `
-     -      ,bMM?.M\\       .    .  -      .      .'
-      :   .oMMMMMMMMb.    ..   `                 .
-       .  `HMMMMMMMMMMb                         -
-        -   9MH*#HMMMMH                        .'
-         '.  '   `\"*##'      `b.              :
-           .         `     .d''             .'
-             -.                          . '
-                -.                    .-`",
-    " 
-                   _oo#H&d#b?\\b:_>>\\_
-               .oHMMMMMMH*\"*9R\"'-``*#P\\-_
-             oHMMMMMMMMM$  .\"       '   `^-
-          .dMMMMMMMMH*\",?-                 '\\.
-         ,MMMMMMM:?}.,d'                     `.
-        dMMMMMMMH  /''                         :
-       HMMMMMMM&' -                             -
-      dPTMMP>' :                           -.    :
-     |? -MM}  .\\                                  .
-     J' ::*'  -$L                                 .
-     :  ?b .,H- '                                 :
-     -  |6.&MP:: !.,_.                            -
-     :   `\\:: \"' \"`:\"MM#,-^,            -         :
-     -     ````:' _.:\"?``\\   `-                   .
-     :         .?bMML.]#        -   _  `      .  .'
-      -      .o#MMMMMMMMH\\     \\.          .     .
-       -     `HMMMMMMMMMMMH                     :
-       `.     `HMM#*#MMMMMH'                   -
-         -.     '    ``##*'      i+           :
-           -            `'     v/'          .'
-            `-                           ..'
-               ' .                    .-",
-    " 
-                   _oo##Mbb&bo??o_>\\_
-               .oHMMMMMMMMM**#?M*' \"?*&..
-             oHMMMMMMMMMMMM4  `\"      -  `.
-          .dMMMMMMMMMMMM#\"\\?.-              .
-         ,MMMMMMMMMM}\"9:_,d'                 -.
-        dMMMMMMMMMMM|  ^''                     .
-       &MMMMMMMMMMH\\  -                         .
-      :{M*\"MMMPT\"' :                         `-. :
-     .'M'  'MMM.  -T,       .                     .
-     - k   i:?''  -|&                             .
-     : `  -o&  .,H- \"                             :
-     -     `M:`HMP|:'!.o._.                       .
-     :      \"<:::'<^ '\"``9MH#,-^ .                -
-     -         '''``''._.`\"?`^|   ^        -      :
-     :              ?#dMM_.M?       .   .  -    ..'
-      :          ,ddMMMMMMMMMb.    ..   '        .
-       .         TMMMMMMMMMMMMM,                :
-        -         ?MMH**#MMMMMH'               :
-         '.        '     \"`##*'      &.       :
-           -.               `'    ,~\"       .'
-             -.                          ..'
-               ` .                    .-",
-    " 
-                   _,dd#HMb&dHo?\\?:\\_
-               .oHMMMMMMMMMMMH***9P'`\"\\v.
-             oHMMMMMMMMMMMMMMM>  `'      -.
-          .dMMMMMMMMMMMMMMMH*'|~-'          .
-         ,MMMMMMMMMMMMM6>`H._,&              -.
-        dMMMMMMMMMMMMMMM|  `\"                  .
-       H*MMMMMMMMMMMMMH&. -                     .
-      d' HMM\"\"&MMMPT'' :.                      `.-
-     ,'  MP   `TMMM,   |:        .                -
-     |   #:    ? *\"   : &L                        :
-     !   `'   /?H   ,#r `'                        :
-     .         ?M: HMM^<~->,o._                   :
-     :          `9:::'`*-``':`9MHb,|-,         '  :
-     .             `\"''':' :_ \"\"!\"^.  `|          :
-     `.                 _dbHM6_|H.      .   . '  .'
-      \\              _odHMMMMMMMMH,    ..  `     :
-      `-             |MMMMMMMMMMMMM|            :
-       `.             9MMH**#MMMMMH'           :
-         -.            '     \"?##\"      d     :
-           .                    '    ,/\"    .'
-            `..                          ..'
-               `  .                   .-",
-    " 
-                   _oo##bHMb&d#bd,>\\_
-               .oHMMMMMMMMMMMMMM***9R\"-..
-             oHMMMMMMMMMMMMMMMMMH\\  ?   `-.
-          .dMMMMMMMMMMMMMMMMMMM#\".}-'       .
-         ,MMMMMMMMMMMMMMMMM6/`H _o}          -.
-        dMMMMMMMMMMMMMMMMMMML  `''             .
-       HbP*HMMMMMMMMMMMMMMM*: -                 ,
-      dMH' `MMMP'\"HMMMR'T\"  :                    :
-     |H'   -MR'   `?MMMb    P,       .            .
-     1&     *|     |.`*\"  .-`&|                   .
-     M'      \"    |\\&|  .,#~ \"'                   :
-     T             :HL.|HMH\\c~`|v,\\_              :
-     |              `\"|:::':`-`` '\"MM#\\-'.       -:
-     %                 ``'``'`' :_ '?'`| ``.      :
-     ||,                     ,#dMM?.M?      .  .` -
-      ?\\                 .,odMMMMMMMMM?    \\  `  :
-       /                 |MMMMMMMMMMMMM:        .'
-       `.                 TMMH#*9MMMMM*        :
-         -.               `      \"*#*'    ,:  .
-           .                       `   .v'' .'
-            `.                           ..'
-               '- .                   .-",
-    " 
-                   _,,>#b&HMHd&&bb>\\_
-               _oHMMMMMMMMMMMMMMMMH**H:.
-             oHMMMMMMMMMMMMMMMMMMMM#v`?  `.
-          .dMMMMMMMMMMMMMMMMMMMMMMH*`+|     .
-         ,MMMMMMMMMMMMMMMMMMMMMb|?+.,H       -.
-        ddHMMMMMMMMMMMMMMMMMMMMMb  `'          .
-       HMMkZ**HMMMMMMMMMMMMMMMMH\\  -   .        :
-      dTMMM*  `9MMMP'\"*MMMMPT\"` ..               :
-     |M6H''    4MP'   `\"HMMM|   !|.      .        .
-     1MHp'      #L      $ *\"'  .-:&.              .
-     MMM'        \"     q:H.  .o#-``'              :
-     MM'                ?H?.|MMH::::-o,_.         -
-     M[                  `*?:::'|` `\"`:9MH\\~-.    `
-     &M.                     \"\"'`'^'.:.`?'`. '|  -:
-     `M|d,                       .dbHM[.1?     .. :
-      9||| .                  _obMMMMMMMMH,   .  :
-       H.^                    MMMMMMMMMMMM}     -
-        \\                     |MMH#*HMMMMH'    .'
-         .                    `      `#*'   ,:-
-          `                           '' .-'.
-            `.                           .-
-               '- .                   .-`",
-    " 
-                   .,:,#&6dHHHb&##o\\_
-               .oHHMMMMMMMMMMMMMMMMMH*\\,.
-             oHMMMMMMMMMMMMMMMMMMMMMMHb:'-.
-          .dMMMMMMMMMMMMMMMMMMMMMMMMMH|\\/'  .
-         ,&HMMMMMMMMMMMMMMMMMMMMMMM/\"&.,d.   -.
-        dboMMHMMMMMMMMMMMMMMMMMMMMMML `'       .
-       HMHMMM$Z***MMMMMMMMMMMMMMMMMM|.-         .
-      dMM}MMMM#'  `9MMMH?\"`MMMMR'T'  _           :
-     |MMMbM#''     |MM\"    ``MMMH.   <_           .
-     dMMMM#&        *&.     .?`*\"   .'&:          .
-     MMMMMH-         `'    -v/H   .dD \"'  '       :
-     MMMM*                  `*M: 4MM*::-!v,_      :
-     MMMM                     `*?::\" \"'``\"?9Mb::. :
-     &MMM,                       `\"'\"'|\"._ \"?`| - :
-     `MMM}.H                          ,#dM[_H   ..:
-      9MMi`M: .                   .ooHMMMMMMM,  ..
-       9Mb `-                     1MMMMMMMMMM|  :
-        ?M                        |MM#*#MMMM*  .
-         -.                       `     |#\"' ,'
-           .                            -\" v`
-             -.                          .-
-                - .                   . `",
-    " 
-                   _,<_:&S6dHHHb&bb\\_
-               .odHMMMMMMMMMMMMMMMMMMM}-_
-            .oHMMMMMMMMMMMMMMMMMMMMMMMM#d:.
-           ?9MMMMMMMMMMMMMMMMMMMMMMMMMMMH-$ .
-         ,::dHMMMMMMMMMMMMMMMMMMMMMMMMH:\\.?? -.
-        dMdboHMMHMMMMMMMMMMMMMMMMMMMMMMH, '    .
-       HMMMM7MMMb$R***MMMMMMMMMMMMMMMMMH\\ -     .
-      dMMMMM/MMMMM*   `$MMMM*'\"*MMMM?&'  .       :
-     |MMMMMMb1H*'       HMP'    '9MMM|   &.    .  .
-     dMMMMMMM##~`       `#\\      |.`*\"  .-9.      :
-     9MMMMMMMM*           `     |v7?  .,H `' `    :
-     SMMMMMMH'                   '9M_-MMH::-\\v_   :
-     :HMMMMM                       `\\_:\"'|'`':9Mv\\.
-     -|MMMMM,                         \"\"`'`':.`?\\ \\
-     `:MMMMM}.d}                         .?bM6,|  |
-      :?MMM6  M|  .                   .,oHMMMMM| /
-       .?MMM- `'                      &MMMMMMMM|.
-        -`HM-                         HMH#*MMM?:
-         '.                           '   `#*:`
-           -                              -'/
-            ` .                          . '
-               ` .                    . `",
-    " 
-                   _o,d_?dZdoHHHb#b\\_
-               .vdMMMMMMMMMMMMMMMMMMMMH\\.
-            .,HHMMMMMMMMMMMMMMMMMMMMMMMMH&,.
-           /?RMMMMMMMMMMMMMMMMMMMMMMMMMMMMH|..
-         ,\\?>`T#RMMMMMMMMMMMMMMMMMMMMMMMM6`\\|/
-        dMMbd#ooHMMMHMMMMMMMMMMMMMMMMMMMMMH,`' '
-       HMMMMMMMTMMMMb$ZP**HMMMMMMMMMMMMMMMM|.   :
-      dMMMMMMMM}$MMMMMH'   `HMMMH?\"`MMMM?T' .    :
-     |MMMMMMMMMMoMH*''      `MM?    ``MMM|  +\\    .
-     1MMMMMMMMMMMb#/         ?#?      |`#\"  -T:   :
-     *'HMMMMMMMMMM*'           \"     ~?&  .?} ' ' .
-     - 4MMMMMMMMP\"                    `M? HMTc:\\\\.:
-     : `MMMMMMM[                       \"#:::`>`\"?M{
-     .  |MMMMMMH.                        ``'``'_`:-
-     -  |MMMMMMM|.dD                         ,#Mb\\'
-      :  *MMMMM: iM|  .                   _oHMMMM:
-       .  ?MMMM'  \"'                     ,MMMMMMP
-        :  `HMH                          JM#*MMT
-         -.  '                           `   #'
-           .                                /
-             -.            -              .'
-                -.                    . `",
-    " 
-                   .-:?,Z?:&$dHH##b\\_
-                ,:bqRMMMMMMMMMMMMMMMMMHo.
-             .?HHHMMMMMMMMMMMMMMMMMMMMMMMHo.
-           -o/*M9MMMMMMMMMMMMMMMMMMMMMMMMMMMv
-         .:H\\b\\'|?#HHMMMMMMMMMMMMMMMMMMMMMM6?Z\\
-        .?MMMHbdbbodMMMMHMMMMMMMMMMMMMMMMMMMM\\':
-       :MMMMMMMMMMM7MMMMb?6P**#MMMMMMMMMMMMMMM_ :
-      \\MMMMMMMMMMMMb^MMMMMM?   `*MMMM*\"`MMMR<' . -
-     .1MMMMMMMMMMMMMb]M#\"\"       9MR'   `?MMb  \\. :
-     -MMMMMMMMMMMMMMMH##|`        *&.     |`*' .\\ .
-     -?\"\"*MMMMMMMMMMMMM'            '    |?b  ,}\" :
-     :    MMMMMMMMMMH'                    `M_|M}r\\?
-     .    `MMMMMMMMM'                      `$_:`'\"H
-     -     TMMMMMMMM,                        '\"``::
-     :     {MMMMMMMM| oH|                      .#M-
-      :    `9MMMMMM' .MP   .                 ,oMMT
-       .     HMMMMP'  `'                    ,MMMP
-        -     `MMH'                         HH9*
-         '.    `                           ` .'
-           -                               . '
-            ` .               -          .-
-               ` .                    .-",
-    " 
-                   _..-:b&::&?&&##bo_
-               ...?-#&9MMMMMMMMMMMMMMMHo_
-            .. .1&#MMHMMMMMMMMMMMMMMMMMMMHo.
-          .  .o/##R9MMMMMMMMMMMMMMMMMMMMMMMM?.
-         .- |MSd?|'`$?#HMMMMMMMMMMMMMMMMMMMMMH\\
-        -  dMMMMHbd##oodMMMM#MMMMMMMMMMMMMMMMMH:
-       - JMMMMMMMMMMMMM7HMMMH$SR***MMMMMMMMMMMMb>
-      : {MMMMMMMMMMMMMMM`9MMMMMH'  ``HMMM?\"*MM[| :
-     - |MMMMMMMMMMMMMMMMM<MH*''      `MM'   'HM? |.
-     : `MMMMMMMMMMMMMMMMMM##H-'       `#,  ` |`? /|
-     .  ?\"\"\"?HMMMMMMMMMMMMMH'           \"    v& .}?
-     -       |MMMMMMMMMMMP'                  `H:&H&
-     i       `9MMMMMMMMMT                     `|?\"?
-     :         MMMMMMMMMH                       \"`\\
-     :         MMMMMMMMMH-.dH                    ,|
-      :        ?MMMMMMM?  {M' .                .dT
-       .        ?MMMMMR'  `'                  ,MP
-        -        `HMM#'                      .&*
-         '.        '                         `.
-           -                               . '
-            `..                          .-
-                -.                    .-",
-    " 
-                   _v---:?&?:?&?&#b\\_
-               ..' i: #M$MMMMMMMMMMMMMHo_
-            ..   -]M#HMHMMMMMMMMMMMMMMMMMHo.
-          .     ooP*&6&MMMMMMMMMMMMMMMMMMMMM?.
-         . -   &Rbbd-/`?:##HMMMMMMMMMMMMMMMMMH?
-        -    ,HMMMMM#od#boodMMMMHMMMMMMMMMMMMMMb
-       -   iMMMMMMMMMMMMMMM[*MMMH&$R***MMMMMMMMMb
-      :   |MMMMMMMMMMMMMMMMML\"MMMMMM'  ``MMMP\"HMM:
-     .    HMMMMMMMMMMMMMMMMMMb/MH\"\"      `MR   *M,|
-     :    TMMMMMMMMMMMMMMMMMMMMd#&`       `D.   ?|\\
-     .     `*\"'\"*HMMMMMMMMMMMMMMP'          '  -d,J
-     :           |MMMMMMMMMMMMP'                ||M
-     M,           ?MMMMMMMMMM|                  `\\?
-     &|            HMMMMMMMMM}                   ``
-     `L           .MMMMMMMMMMP ,d|                :
-      *.           ?MMMMMMMF' .MP                /
-       |            TMMMMMM'  `\"'               /
-       `.            `MMMP'                   ./
-         -.           `                       .
-           .                               . '
-             - .                         .-
-                -\\                   ..-",
-    " 
-                   _vo~^'':&b::d,#b\\_
-               ..`\" `:v +9P]MMMMMMMMMMHo_
-             ,-     ?Mb#MMMMMMMMMMMMMMMMMHo.
-          . \"     ,ooM*&&&HMMMMMMMMMMMMMMMMHb.
-         .   -    99Soo?|'`*?##HMMMMMMMMMMMMMH\\
-        -       .HMMMMMM#od#boodMMMMHMMMMMMMMMMb
-       -      :MMMMMMMMMMMMMMMM67HMMH&$R**HMMMMMb
-      :      .MMMMMMMMMMMMMMMMMMM/HMMMMM|  `9MM'HL
-     :       {MMMMMMMMMMMMMMMMMMMM\\MM*''    `H[ `9|
-     |       `HMMMMMMMMMMMMMMMMMMMMb##|      `F. :?
-     H        `\"*\"'\"`#MMMMMMMMMMMMMMM?         '  k
-     M.               MMMMMMMMMMMMM\"'             H
-     MMH.             `HMMMMMMMMMM:               |
-     &MM|              `MMMMMMMMMM,               -
-     `MM|              dMMMMMMMMMM|.oH            :
-      9ML              `HMMMMMMM?  dH'           -
-       Hi               |MMMMMMP   \"'           .'
-        T.               `MMM#'                -
-         `.               `                  .`
-          `                                 -
-            `.. .                        ..'
-                ...                   .-",
-    " 
-                   _ooq=\"\"''$b$_&?b\\_
-               .-`^\"  \"'o |&M:MMMMMMMMHo_
-             o/'      -$Mb#MMMMMMMMMMMMMMHo.
-           /'        .ooHP*&R&MMMMMMMMMMMMMM?.
-         .'          `MRbod?|'`+?##9MMMMMMMMMH\\
-       .`          .,MMMMMMH#od##obdMMMMHMMMMMMb
-       -          ?MMMMMMMMMMMMMMMMM$HMMH$ZP*HMMb
-      ?          |MMMMMMMMMMMMMMMMMMM|9MMMMP  \"M6\\
-     .-          dMMMMMMMMMMMMMMMMMMMMb]M*'    |R |
-     1|          `HMMMMMMMMMMMMMMMMMMMMMd#|     ?,:
-     MH,          ``*\"\"'\"*#MMMMMMMMMMMMMM*       '`
-     MM6_                 |MMMMMMMMMMMMH\"         :
-     MMMMMb.               \"MMMMMMMMMMT           -
-     &MMMMM'                |MMMMMMMMMH           `
-     !MMMMb                .HMMMMMMMMM+.?&        :
-      TMMMM                 *MMMMMMMP  dH' .     :
-       9MM'                 `MMMMMMP'  \"'       .
-        9ML                  `MMM#'            -
-         `H                   `               :
-          `\\.                               .'
-            `-\\  .                       .-",
-];
+/// Procedural rotating globe: projects the embedded land mask onto a shaded sphere.
+#[derive(Clone, Debug)]
+pub struct Globe {
+    pub width: usize,
+    pub height: usize,
+    /// Degrees of rotation between consecutive frames.
+    pub speed: f32,
+    /// Degrees the north pole is tipped toward the viewer.
+    pub tilt: f32,
+    /// Land characters from darkest to brightest.
+    pub land_ramp: Vec<char>,
+    /// Ocean characters from darkest to brightest; a single space leaves the ocean blank.
+    pub ocean_ramp: Vec<char>,
+    /// Draw the rim of the sphere with `.`, `-` and `:` where it crosses open ocean.
+    pub limb: bool,
+    /// Pick land characters by coverage instead of lighting, like the hand-drawn globe.
+    pub flat: bool,
+    /// Width of a terminal cell divided by its height.
+    pub cell_aspect: f32,
+    /// Points fixed to the surface; hidden while on the far side.
+    pub markers: Vec<Marker>,
+}
+
+impl Default for Globe {
+    fn default() -> Self {
+        Self {
+            width: 48,
+            height: 23,
+            speed: 12.0,
+            tilt: 15.0,
+            land_ramp: ":-=+*#%@".chars().collect(),
+            ocean_ramp: " .".chars().collect(),
+            limb: false,
+            flat: false,
+            cell_aspect: 0.5,
+            markers: vec![],
+        }
+    }
+}
+
+/// Sub-samples per cell along each axis.
+const SAMPLES: usize = 3;
+
+impl Globe {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Settings that mimic the original hand-drawn 30-frame globe animation.
+    pub fn classic() -> Self {
+        Self {
+            width: 48,
+            height: 23,
+            speed: 12.0,
+            tilt: 20.0,
+            land_ramp: " .:?*&#HM".chars().collect(),
+            ocean_ramp: vec![' '],
+            limb: true,
+            flat: true,
+            cell_aspect: 0.5,
+            markers: vec![],
+        }
+    }
+
+    pub fn with_size(mut self, width: usize, height: usize) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    pub fn with_tilt(mut self, tilt: f32) -> Self {
+        self.tilt = tilt;
+        self
+    }
+
+    pub fn with_land_ramp(mut self, ramp: &str) -> Self {
+        self.land_ramp = ramp.chars().collect();
+        self
+    }
+
+    pub fn with_ocean_ramp(mut self, ramp: &str) -> Self {
+        self.ocean_ramp = ramp.chars().collect();
+        self
+    }
+
+    pub fn with_limb(mut self, limb: bool) -> Self {
+        self.limb = limb;
+        self
+    }
+
+    pub fn with_marker(mut self, marker: Marker) -> Self {
+        self.markers.push(marker);
+        self
+    }
+
+    pub fn with_markers(mut self, markers: Vec<Marker>) -> Self {
+        self.markers = markers;
+        self
+    }
+
+    /// Number of frames in one full revolution.
+    pub fn frame_count(&self) -> usize {
+        if self.speed == 0.0 {
+            1
+        } else {
+            (360.0 / self.speed.abs()).round().max(1.0) as usize
+        }
+    }
+
+    /// All frames of one revolution.
+    pub fn frames(&self) -> Vec<String> {
+        (0..self.frame_count()).map(|i| self.frame(i)).collect()
+    }
+
+    /// Frame `index` of the animation.
+    pub fn frame(&self, index: usize) -> String {
+        self.render(index as f32 * self.speed)
+    }
+
+    /// Frame `index` of the animation, with marker styles.
+    pub fn frame_text(&self, index: usize) -> Text {
+        self.render_text(index as f32 * self.speed)
+    }
+
+    /// Sphere radius in rows, and the center of the sphere in (columns, rows).
+    pub(crate) fn geometry(&self) -> (f32, f32, f32) {
+        let radius = (self.height as f32 / 2.0).min(self.width as f32 * self.cell_aspect / 2.0);
+        (
+            radius.max(0.5),
+            self.width as f32 / 2.0,
+            self.height as f32 / 2.0,
+        )
+    }
+
+    /// Project cell-space point (`col`, `row`) onto the sphere turned to `rotation` degrees.
+    /// Returns (lat, lon, z) where `z` is the depth toward the viewer, or `None` off the sphere.
+    pub(crate) fn unproject(&self, col: f32, row: f32, rotation: f32) -> Option<(f32, f32, f32)> {
+        let (radius, cx, cy) = self.geometry();
+        let x = (col - cx) * self.cell_aspect / radius;
+        let y = (cy - row) / radius;
+        let r2 = x * x + y * y;
+        if r2 > 1.0 {
+            return None;
+        }
+        let z = (1.0 - r2).sqrt();
+        // undo the tilt toward the viewer (rotation about the x axis)
+        let t = self.tilt.to_radians();
+        let (yy, zz) = (y * t.cos() + z * t.sin(), -y * t.sin() + z * t.cos());
+        let lat = yy.clamp(-1.0, 1.0).asin() * 180.0 / PI;
+        let lon = x.atan2(zz) * 180.0 / PI + rotation;
+        Some((lat, lon, z))
+    }
+
+    /// Project `lat`/`lon` onto the sphere turned to `rotation` degrees.
+    /// Returns the (column, row) cell, or `None` if the point is on the far side.
+    pub fn project(&self, lat: f32, lon: f32, rotation: f32) -> Option<(usize, usize)> {
+        let (radius, cx, cy) = self.geometry();
+        let (lat, lon) = (lat.to_radians(), (lon - rotation).to_radians());
+        let (x, yy, zz) = (lat.cos() * lon.sin(), lat.sin(), lat.cos() * lon.cos());
+        let t = self.tilt.to_radians();
+        let (y, z) = (yy * t.cos() - zz * t.sin(), yy * t.sin() + zz * t.cos());
+        if z <= 0.0 {
+            return None;
+        }
+        let col = cx + x * radius / self.cell_aspect;
+        let row = cy - y * radius;
+        if col < 0.0 || row < 0.0 {
+            return None;
+        }
+        Some((col as usize, row as usize))
+    }
+
+    /// Render the globe turned by `rotation` degrees of longitude.
+    pub fn render(&self, rotation: f32) -> String {
+        canvas_string(&self.canvas(rotation))
+    }
+
+    /// Render the globe turned by `rotation` degrees, with marker styles.
+    pub fn render_text(&self, rotation: f32) -> Text {
+        canvas_text(&self.canvas(rotation))
+    }
+
+    fn canvas(&self, rotation: f32) -> Canvas {
+        let (radius, cx, cy) = self.geometry();
+        let light = normalize((-0.4, 0.5, 0.75));
+        let mut rows: Canvas = Vec::with_capacity(self.height);
+        for row in 0..self.height {
+            let mut line = Vec::with_capacity(self.width);
+            for col in 0..self.width {
+                let (mut hits, mut land, mut land_light, mut ocean_light) = (0, 0, 0.0, 0.0);
+                for sy in 0..SAMPLES {
+                    for sx in 0..SAMPLES {
+                        let c = col as f32 + (sx as f32 + 0.5) / SAMPLES as f32;
+                        let r = row as f32 + (sy as f32 + 0.5) / SAMPLES as f32;
+                        if let Some((lat, lon, z)) = self.unproject(c, r, rotation) {
+                            hits += 1;
+                            let x = (c - cx) * self.cell_aspect / radius;
+                            let y = (cy - r) / radius;
+                            let shade = (x * light.0 + y * light.1 + z * light.2).max(0.0);
+                            if is_land(lat, lon) {
+                                land += 1;
+                                land_light += shade;
+                            } else {
+                                ocean_light += shade;
+                            }
+                        }
+                    }
+                }
+                let total = (SAMPLES * SAMPLES) as f32;
+                let ch = if hits == 0 {
+                    ' '
+                } else if self.limb && land == 0 && (hits as f32) < total {
+                    limb_char(col as f32 + 0.5 - cx, cy - row as f32 - 0.5)
+                } else if self.flat {
+                    pick(&self.land_ramp, land as f32 / total).unwrap_or(' ')
+                } else if land * 2 >= hits {
+                    pick(&self.land_ramp, land_light / land as f32).unwrap_or(' ')
+                } else {
+                    let ocean = (hits - land) as f32;
+                    pick(&self.ocean_ramp, ocean_light / ocean).unwrap_or(' ')
+                };
+                line.push((ch, Style::default()));
+            }
+            rows.push(line);
+        }
+        for marker in &self.markers {
+            let pos = marker.position;
+            if let Some((col, row)) = self.project(pos.lat, pos.lon, rotation) {
+                place_marker(&mut rows, marker, col, row);
+            }
+        }
+        rows
+    }
+}
+
+/// Rim character for a cell at offset (`x` columns, `y` rows) from the center.
+fn limb_char(x: f32, y: f32) -> char {
+    // columns are roughly half as wide as rows are tall
+    let (x, y) = (x.abs() / 2.0, y.abs());
+    if y > x * 2.0 {
+        '.'
+    } else if y > x * 0.5 {
+        '-'
+    } else {
+        ':'
+    }
+}
+
+fn normalize((x, y, z): (f32, f32, f32)) -> (f32, f32, f32) {
+    let len = (x * x + y * y + z * z).sqrt();
+    (x / len, y / len, z / len)
+}
+
+/// Character at `level` (`0.0..=1.0`) along `ramp`.
+fn pick(ramp: &[char], level: f32) -> Option<char> {
+    if ramp.is_empty() {
+        return None;
+    }
+    let idx = (level.clamp(0.0, 1.0) * (ramp.len() - 1) as f32).round() as usize;
+    Some(ramp[idx])
+}
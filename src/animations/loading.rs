@@ -1,4 +1,4 @@
-pub const LOADING: [&'static str; 10] = [
+pub const LOADING: [&str; 10] = [
     r"⠋", //
     r"⠙", //
     r"⠹", //
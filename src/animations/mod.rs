@@ -1,9 +1,15 @@
-use self::{camera::CAMERA, globe::GLOBE, loading::LOADING};
+use self::{camera::CAMERA, loading::LOADING};
+use std::sync::OnceLock;
 
 mod camera;
 mod globe;
 mod loading;
+mod spinners;
 
+pub use globe::Globe;
+pub use spinners::{spinner, SpinnerStyle, SPINNERS};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Animation {
     Globe,
     Camera,
@@ -11,11 +17,42 @@ pub enum Animation {
 }
 
 impl Animation {
+    pub const ALL: [Animation; 3] = [Animation::Globe, Animation::Camera, Animation::Loading];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Animation::Globe => "globe",
+            Animation::Camera => "camera",
+            Animation::Loading => "loading",
+        }
+    }
+
+    /// Look up an animation by its `name`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|a| a.name() == name)
+    }
+
     pub fn frames(&self) -> Vec<&'static str> {
         match self {
             Animation::Camera => CAMERA.to_vec(),
-            Animation::Globe => GLOBE.to_vec(),
+            Animation::Globe => {
+                static CLASSIC: OnceLock<Vec<String>> = OnceLock::new();
+                CLASSIC
+                    .get_or_init(|| Globe::classic().frames())
+                    .iter()
+                    .map(|f| f.as_str())
+                    .collect()
+            }
             Animation::Loading => LOADING.to_vec(),
         }
     }
+
+    /// Frames that fit in `width` x `height` cells; the globe is rendered at that size,
+    /// fixed-size animations are returned as-is.
+    pub fn frames_sized(&self, width: usize, height: usize) -> Vec<String> {
+        match self {
+            Animation::Globe => Globe::classic().with_size(width, height).frames(),
+            _ => self.frames().iter().map(|f| f.to_string()).collect(),
+        }
+    }
 }
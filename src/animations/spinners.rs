@@ -0,0 +1,75 @@
+use super::loading::LOADING;
+use std::time::Duration;
+
+/// A named spinner: its frames and how long each one should be shown.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SpinnerStyle {
+    pub name: &'static str,
+    pub frames: &'static [&'static str],
+    pub interval: Duration,
+}
+
+const fn style(
+    name: &'static str,
+    frames: &'static [&'static str],
+    interval_ms: u64,
+) -> SpinnerStyle {
+    SpinnerStyle {
+        name,
+        frames,
+        interval: Duration::from_millis(interval_ms),
+    }
+}
+
+/// All built-in spinner styles.
+pub const SPINNERS: &[SpinnerStyle] = &[
+    style("dots", &LOADING, 80),
+    style("dots2", &["⣾", "⣽", "⣻", "⢿", "⡿", "⣟", "⣯", "⣷"], 80),
+    style("line", &["-", "\\", "|", "/"], 130),
+    style("pipe", &["┤", "┘", "┴", "└", "├", "┌", "┬", "┐"], 100),
+    style("arc", &["◜", "◠", "◝", "◞", "◡", "◟"], 100),
+    style("circle", &["◐", "◓", "◑", "◒"], 120),
+    style("square", &["◰", "◳", "◲", "◱"], 180),
+    style("triangle", &["◢", "◣", "◤", "◥"], 50),
+    style("bounce", &["⠁", "⠂", "⠄", "⡀", "⢀", "⠠", "⠐", "⠈"], 120),
+    style(
+        "bouncing_bar",
+        &[
+            "[    ]", "[=   ]", "[==  ]", "[=== ]", "[ ===]", "[  ==]", "[   =]", "[    ]",
+            "[   =]", "[  ==]", "[ ===]", "[====]", "[=== ]", "[==  ]", "[=   ]",
+        ],
+        80,
+    ),
+    style(
+        "bouncing_ball",
+        &[
+            "( ●    )",
+            "(  ●   )",
+            "(   ●  )",
+            "(    ● )",
+            "(     ●)",
+            "(    ● )",
+            "(   ●  )",
+            "(  ●   )",
+            "( ●    )",
+            "(●     )",
+        ],
+        80,
+    ),
+    // quarter-circle faces rather than clock emoji, which are two cells wide
+    style("clock", &["◴", "◷", "◶", "◵"], 100),
+    style("arrows", &["←", "↖", "↑", "↗", "→", "↘", "↓", "↙"], 100),
+    style("toggle", &["⊶", "⊷"], 250),
+    style(
+        "grow",
+        &["▁", "▃", "▄", "▅", "▆", "▇", "▆", "▅", "▄", "▃"],
+        120,
+    ),
+    style("star", &["✶", "✸", "✹", "✺", "✹", "✷"], 70),
+    style("simple_dots", &[".  ", ".. ", "...", "   "], 400),
+];
+
+/// Look up a built-in spinner style by name.
+pub fn spinner(name: &str) -> Option<&'static SpinnerStyle> {
+    SPINNERS.iter().find(|s| s.name == name)
+}
@@ -0,0 +1,224 @@
+//! Application runtime: implement `App` and let `Runtime` handle the terminal session,
+//! input, resizing, timing and rendering.
+
+use crate::frame::console::{ConsoleFrame, ConsoleWriter};
+use crate::frame::{FrameClock, FrameConfig, FrameEngine, FrameRender, Text};
+use crate::input::{Action, Input, InputEvent, KeyCode, KeyModifiers, MouseCapture, RawMode};
+use anyhow::Result;
+use crossterm::cursor::{Hide, Show};
+use crossterm::execute;
+use crossterm::terminal::{EnterAlternateScreen, LeaveAlternateScreen};
+
+/// What an `App` is told about.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AppEvent {
+    /// A new frame is due; carries the frame number, which skips ahead if drawing fell behind.
+    Tick(u64),
+    Input(InputEvent),
+}
+
+/// Whether the runtime should keep going after an event.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Control {
+    Continue,
+    Quit,
+}
+
+/// An interactive program drawn in a frame.
+pub trait App {
+    /// React to an event; return `Control::Quit` to end the run.
+    fn update(&mut self, event: AppEvent) -> Control;
+    /// Content to draw in the frame.
+    fn view(&self) -> Text;
+    /// Frame layout; the width and height are replaced with the runtime's frame size.
+    fn frame_config(&self) -> FrameConfig {
+        FrameConfig::default()
+    }
+}
+
+/// Runs an `App` in the terminal at a target frame rate.
+///
+/// `Action::Quit` ends the run, as does `ctrl+c` unless `interrupt` is off. Scroll actions
+/// and mouse wheel events scroll the frame before being passed on to the app.
+#[derive(Clone, Debug)]
+pub struct Runtime {
+    pub fps: f32,
+    pub input: Input,
+    pub mouse: bool,
+    /// Draw on the terminal's alternate screen, leaving the scrollback untouched.
+    pub alternate_screen: bool,
+    /// Fixed frame size, instead of following the terminal.
+    pub size: Option<(usize, usize)>,
+    /// Draw to `/dev/tty` instead of stdout.
+    pub tty: bool,
+    /// Quit on `ctrl+c` even if the keymap doesn't bind it, since raw mode stops it from
+    /// raising SIGINT. Turn off to pass it to the app, e.g. for a terminal pane.
+    pub interrupt: bool,
+}
+
+impl Default for Runtime {
+    fn default() -> Self {
+        Self {
+            fps: 30.0,
+            input: Input::default(),
+            mouse: false,
+            alternate_screen: true,
+            size: None,
+            tty: false,
+            interrupt: true,
+        }
+    }
+}
+
+impl Runtime {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_fps(mut self, fps: f32) -> Self {
+        self.fps = fps;
+        self
+    }
+
+    pub fn with_input(mut self, input: Input) -> Self {
+        self.input = input;
+        self
+    }
+
+    pub fn with_mouse(mut self, mouse: bool) -> Self {
+        self.mouse = mouse;
+        self
+    }
+
+    pub fn with_alternate_screen(mut self, alternate_screen: bool) -> Self {
+        self.alternate_screen = alternate_screen;
+        self
+    }
+
+    pub fn with_size(mut self, width: usize, height: usize) -> Self {
+        self.size = Some((width, height));
+        self
+    }
+
+    pub fn with_tty(mut self, tty: bool) -> Self {
+        self.tty = tty;
+        self
+    }
+
+    pub fn with_interrupt(mut self, interrupt: bool) -> Self {
+        self.interrupt = interrupt;
+        self
+    }
+
+    /// Run `app` until it quits. The terminal is restored afterwards, even on error.
+    pub fn run<A: App>(&self, app: &mut A) -> Result<()> {
+        let console = if self.tty {
+            ConsoleFrame::tty()?
+        } else {
+            ConsoleFrame::new()
+        };
+        let _session = Session::start(console.writer(), self.alternate_screen)?;
+        let _mouse = if self.mouse {
+            Some(MouseCapture::enable_on(console.writer())?)
+        } else {
+            None
+        };
+        let mut engine = console.new_frame_engine(&app.frame_config());
+        if let Some((width, height)) = self.size {
+            engine.resize(width, height);
+        }
+        let mut clock = FrameClock::new(self.fps);
+        loop {
+            // handle input until the next frame is due
+            while let Some(event) = self.input.poll(clock.until_next())? {
+                if self.is_quit(&event) {
+                    return Ok(());
+                }
+                match event {
+                    InputEvent::Resize(..) if self.size.is_some() => {}
+                    _ => handle_input(&mut engine, &event),
+                }
+                if app.update(AppEvent::Input(event)) == Control::Quit {
+                    return Ok(());
+                }
+            }
+            let frame = clock.tick();
+            if app.update(AppEvent::Tick(frame)) == Control::Quit {
+                return Ok(());
+            }
+            let config = app
+                .frame_config()
+                .with_width(engine.config().width)
+                .with_height(engine.config().height);
+            if config != *engine.config() {
+                engine.set_config(&config);
+            }
+            clock.draw(&mut engine, &app.view());
+        }
+    }
+}
+
+impl Runtime {
+    fn is_quit(&self, event: &InputEvent) -> bool {
+        match event {
+            InputEvent::Action(Action::Quit) => true,
+            InputEvent::Key(key) => {
+                self.interrupt
+                    && key.code == KeyCode::Char('c')
+                    && key.modifiers.contains(KeyModifiers::CONTROL)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Apply the input the runtime handles itself; changes show on the next frame.
+fn handle_input<R: FrameRender>(engine: &mut FrameEngine<R>, event: &InputEvent) {
+    let page = engine.content_height() as isize;
+    match event {
+        InputEvent::Action(Action::ScrollUp) => engine.scroll_by(-1),
+        InputEvent::Action(Action::ScrollDown) => engine.scroll_by(1),
+        InputEvent::Action(Action::PageUp) => engine.scroll_by(-page),
+        InputEvent::Action(Action::PageDown) => engine.scroll_by(page),
+        InputEvent::Action(Action::ScrollTop) => engine.scroll_to(0),
+        InputEvent::Action(Action::ScrollBottom) => engine.scroll_to(usize::MAX),
+        InputEvent::Mouse(mouse) => engine.scroll_by(mouse.scroll_delta()),
+        InputEvent::Resize(width, height) => {
+            // leave the last row free, like `ConsoleFrame`
+            engine.resize(*width, height.saturating_sub(1));
+            true
+        }
+        _ => false,
+    };
+}
+
+/// Raw mode, hidden cursor and optionally the alternate screen, undone on drop.
+struct Session {
+    _raw_mode: RawMode,
+    out: ConsoleWriter,
+    alternate_screen: bool,
+}
+
+impl Session {
+    fn start(mut out: ConsoleWriter, alternate_screen: bool) -> Result<Self> {
+        let raw_mode = RawMode::enable()?;
+        if alternate_screen {
+            execute!(out, EnterAlternateScreen)?;
+        }
+        execute!(out, Hide)?;
+        Ok(Self {
+            _raw_mode: raw_mode,
+            out,
+            alternate_screen,
+        })
+    }
+}
+
+impl Drop for Session {
+    fn drop(&mut self) {
+        let _ = execute!(self.out, Show);
+        if self.alternate_screen {
+            let _ = execute!(self.out, LeaveAlternateScreen);
+        }
+    }
+}
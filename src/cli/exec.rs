@@ -0,0 +1,92 @@
+use super::Args;
+use anyhow::{Error, Result};
+use cli_frame::app::{App, AppEvent, Control, Runtime};
+use cli_frame::frame::{FrameConfig, Text};
+use cli_frame::input::{Input, InputEvent, Keymap};
+use cli_frame::terminal::TerminalPane;
+
+/// An interactive program running in the frame; every key goes to the program.
+struct Exec {
+    pane: TerminalPane,
+    command: String,
+    config: FrameConfig,
+    /// The frame size was given on the command line, so it doesn't follow the terminal.
+    fixed: bool,
+    error: Option<Error>,
+}
+
+impl Exec {
+    fn fail(&mut self, err: Error) -> Control {
+        self.error = Some(err);
+        Control::Quit
+    }
+}
+
+impl App for Exec {
+    fn update(&mut self, event: AppEvent) -> Control {
+        let result = match event {
+            AppEvent::Tick(_) => match self.pane.try_wait() {
+                Ok(Some(_)) => return Control::Quit,
+                Ok(None) => Ok(()),
+                Err(err) => Err(err),
+            },
+            AppEvent::Input(InputEvent::Key(key)) => self.pane.send_key(key),
+            AppEvent::Input(InputEvent::Resize(width, height)) if !self.fixed => {
+                let framespace = self.config.framespace();
+                self.pane.resize(
+                    width.saturating_sub(framespace),
+                    height.saturating_sub(1 + framespace),
+                )
+            }
+            _ => Ok(()),
+        };
+        match result {
+            Ok(()) => Control::Continue,
+            Err(err) => self.fail(err),
+        }
+    }
+
+    fn view(&self) -> Text {
+        self.pane.text()
+    }
+
+    fn frame_config(&self) -> FrameConfig {
+        let screen = self.pane.screen();
+        let title = match screen.title() {
+            "" => &self.command,
+            title => title,
+        };
+        self.config.clone().with_title(&format!(" {} ", title))
+    }
+}
+
+pub fn run(args: &Args, command: &str) -> Result<()> {
+    let config = args.frame_config();
+    let (width, height) = args.size();
+    let framespace = config.framespace();
+    let pane = TerminalPane::spawn(
+        command,
+        width.saturating_sub(framespace),
+        height.saturating_sub(framespace),
+    )?;
+    let mut exec = Exec {
+        pane,
+        command: command.to_owned(),
+        config,
+        fixed: args.width.is_some() || args.height.is_some(),
+        error: None,
+    };
+    let runtime = if exec.fixed {
+        Runtime::new().with_size(width, height)
+    } else {
+        Runtime::new()
+    };
+    runtime
+        .with_input(Input::new().with_keymap(Keymap::empty()))
+        .with_interrupt(false)
+        .run(&mut exec)?;
+    match exec.error {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,155 @@
+use super::{view, write_frame, Args};
+use anyhow::{bail, Error, Result};
+use cli_frame::app::{App, AppEvent, Control};
+use cli_frame::follow::{FollowEvent, Follower, LineBuffer, LineStream};
+use cli_frame::frame::console::ConsoleFrame;
+use cli_frame::frame::{FrameConfig, FrameEngine, Text};
+use cli_frame::input::{Action, InputEvent};
+use cli_frame::terminal::interpret;
+use std::path::Path;
+
+/// Where new lines come from.
+enum Source {
+    File(Follower),
+    Stream(LineStream),
+}
+
+/// Shows lines as they're written to a file or stream, pinned to the bottom.
+struct Tail {
+    source: Source,
+    lines: LineBuffer,
+    config: FrameConfig,
+    interpret: bool,
+    help: bool,
+    error: Option<Error>,
+}
+
+impl Tail {
+    fn new(args: &Args, source: Source) -> Self {
+        Self {
+            source,
+            lines: LineBuffer::with_capacity(args.max_lines),
+            config: args.frame_config().with_stick_to_bottom(true),
+            interpret: args.interpret,
+            help: false,
+            error: None,
+        }
+    }
+
+    /// Pick up new lines; false once there's nothing more to wait for.
+    fn poll(&mut self) -> Result<bool> {
+        match &mut self.source {
+            Source::File(follower) => {
+                let update = follower.poll()?;
+                if update.event == Some(FollowEvent::Truncated) {
+                    self.lines.clear();
+                }
+                self.lines.extend(update.lines);
+                Ok(true)
+            }
+            Source::Stream(stream) => {
+                self.lines.extend(stream.poll()?);
+                Ok(!stream.is_closed())
+            }
+        }
+    }
+
+    /// Number of the first line kept, counting those dropped to stay within `max_lines`.
+    fn first_line_number(&self) -> usize {
+        self.lines.dropped() as usize + 1
+    }
+
+    /// Everything read so far, including a file's unterminated last line.
+    fn text(&self) -> Text {
+        // as one piece, so styles and cursor movement can reach across lines
+        let mut content = String::new();
+        for line in self.lines.iter() {
+            content.push_str(line);
+            content.push('\n');
+        }
+        if let Source::File(follower) = &self.source {
+            content.push_str(&follower.partial());
+        }
+        if self.interpret {
+            interpret(&content)
+        } else {
+            Text::from_ansi(&content)
+        }
+    }
+
+    /// Run until quit, or until the stream ends.
+    fn run(&mut self, args: &Args, tty: bool) -> Result<()> {
+        view::runtime(args).with_fps(10.0).with_tty(tty).run(self)?;
+        match self.error.take() {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+}
+
+impl App for Tail {
+    fn update(&mut self, event: AppEvent) -> Control {
+        match event {
+            AppEvent::Tick(_) => match self.poll() {
+                Ok(true) => {}
+                Ok(false) => return Control::Quit,
+                Err(err) => {
+                    self.error = Some(err);
+                    return Control::Quit;
+                }
+            },
+            AppEvent::Input(InputEvent::Action(Action::ToggleHelp)) => self.help = !self.help,
+            _ => {}
+        }
+        Control::Continue
+    }
+
+    fn view(&self) -> Text {
+        if self.help {
+            view::help()
+        } else {
+            self.text()
+        }
+    }
+
+    fn frame_config(&self) -> FrameConfig {
+        if self.help {
+            self.config.clone()
+        } else {
+            self.config
+                .clone()
+                .with_first_line_number(self.first_line_number())
+        }
+    }
+}
+
+/// Follow a file as it grows.
+pub fn run(args: &Args, path: &Path) -> Result<()> {
+    if path.as_os_str() == "-" {
+        bail!("--follow needs a file path, not stdin");
+    }
+    Tail::new(args, Source::File(Follower::open(path)?)).run(args, false)
+}
+
+/// Show stdin as it arrives, drawing on the terminal so stdout stays free, then leave
+/// the last screenful behind as a static frame.
+pub fn run_stdin(args: &Args) -> Result<()> {
+    let mut tail = Tail::new(args, Source::Stream(LineStream::spawn(std::io::stdin())));
+    tail.run(args, true)?;
+
+    let console = ConsoleFrame::tty()?;
+    let (width, height) = args.size_of(&console);
+    let config = args
+        .frame_config()
+        .with_width(width)
+        .with_height(height)
+        .with_first_line_number(tail.first_line_number());
+    let mut engine = FrameEngine::new(&config, console.clone());
+    let lines = engine.layout(&tail.text());
+    let shown = lines.len().min(engine.content_height());
+    if args.height.is_none() {
+        engine.resize(width, shown + engine.framespace());
+    }
+    let frame = engine.compose_lines(&lines[lines.len() - shown..]);
+    write_frame(&mut console.writer(), &frame)
+}
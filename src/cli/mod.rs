@@ -0,0 +1,280 @@
+//! Command-line interface of the `cli-frame` binary.
+
+#[cfg(feature = "pty")]
+mod exec;
+mod follow;
+mod play;
+mod view;
+mod watch;
+
+use anyhow::{bail, Context, Result};
+use clap::Parser;
+use cli_frame::animations::{Animation, SPINNERS};
+use cli_frame::frame::console::ConsoleFrame;
+use cli_frame::frame::constants::{FRAME_BG, SPACE};
+use cli_frame::frame::{FrameConfig, FrameEngine, FrameRender, RenderedFrame, Style, Text};
+use cli_frame::highlight::{Grammar, Highlighter};
+use cli_frame::terminal::interpret;
+use std::io::{self, IsTerminal, Read, Write};
+use std::path::PathBuf;
+
+/// Draw a file, stdin or an animation inside a frame in the terminal.
+#[derive(Parser, Debug)]
+#[command(name = "cli-frame", version)]
+pub struct Args {
+    /// File to show, or `-` to read stdin.
+    pub file: Option<PathBuf>,
+    /// Border thickness in cells.
+    #[arg(short, long, default_value_t = 1)]
+    pub border: usize,
+    /// Space between the border and the content.
+    #[arg(short, long, default_value_t = 1)]
+    pub padding: usize,
+    /// Space around the border.
+    #[arg(short, long, default_value_t = 1)]
+    pub margin: usize,
+    /// Character the border is drawn with.
+    #[arg(long, default_value_t = FRAME_BG)]
+    pub frame_char: char,
+    /// Character empty space is filled with.
+    #[arg(long, default_value_t = SPACE)]
+    pub space_char: char,
+    /// Frame width [default: terminal width].
+    #[arg(long)]
+    pub width: Option<usize>,
+    /// Frame height [default: terminal height, or the content height with --once].
+    #[arg(long)]
+    pub height: Option<usize>,
+    /// Number lines in a gutter left of the content.
+    #[arg(short = 'N', long)]
+    pub line_numbers: bool,
+    /// Keep showing lines as they're appended to FILE, like `tail -F`.
+    #[arg(short, long, requires = "file", conflicts_with = "once")]
+    pub follow: bool,
+    /// Lines kept in memory when following a file or streaming stdin.
+    #[arg(long, value_name = "N", default_value_t = 10_000)]
+    pub max_lines: usize,
+    /// Re-run COMMAND periodically and show its output, like `watch(1)`.
+    #[arg(short, long, value_name = "COMMAND", conflicts_with_all = ["file", "animation", "once"])]
+    pub watch: Option<String>,
+    /// Seconds between runs of the watched command.
+    #[arg(short = 'n', long, value_name = "SECS", default_value_t = 2.0, value_parser = parse_interval)]
+    pub interval: f32,
+    /// Highlight characters that changed since the previous run.
+    #[arg(short, long, requires = "watch")]
+    pub differences: bool,
+    /// Interpret carriage returns, cursor movement and colors in the input, the way a
+    /// terminal would, instead of showing them as is.
+    #[arg(long)]
+    pub interpret: bool,
+    /// Highlight the file as NAME (rust, toml, json, sh), or `none` to turn highlighting
+    /// off [default: from the file extension].
+    #[arg(long, value_name = "NAME", conflicts_with_all = ["follow", "watch", "animation", "interpret"])]
+    pub syntax: Option<String>,
+    /// Run COMMAND in a terminal inside the frame; all keys go to it.
+    #[cfg(feature = "pty")]
+    #[arg(short, long, value_name = "COMMAND", conflicts_with_all = ["file", "watch", "animation", "once", "line_numbers"])]
+    pub exec: Option<String>,
+    /// Play an animation instead of showing a file; see --list-animations.
+    #[arg(short, long, value_name = "NAME", conflicts_with = "file")]
+    pub animation: Option<String>,
+    /// Animation frame rate [default: 10, or the spinner's own rate].
+    #[arg(long, value_parser = parse_fps)]
+    pub fps: Option<f32>,
+    /// Stop after playing the animation this many times.
+    #[arg(long)]
+    pub loops: Option<u64>,
+    /// List the available animations and exit.
+    #[arg(long)]
+    pub list_animations: bool,
+    /// Print a single frame to stdout and exit, without taking over the terminal.
+    #[arg(long)]
+    pub once: bool,
+}
+
+impl Args {
+    fn frame_config(&self) -> FrameConfig {
+        let config = FrameConfig::new()
+            .with_border_thickness(self.border)
+            .with_padding(self.padding)
+            .with_margin(self.margin)
+            .with_frame_char(self.frame_char)
+            .with_space_char(self.space_char)
+            .with_interpret_escapes(self.interpret)
+            .with_line_numbers(self.line_numbers);
+        // keep piped output free of escapes the content didn't bring
+        if io::stdout().is_terminal() {
+            config
+        } else {
+            config.with_gutter_style(Style::default())
+        }
+    }
+
+    /// The grammar to highlight the input with: the one asked for, else the one for the
+    /// file's extension when drawing on a terminal and not interpreting escapes.
+    fn grammar<'h>(&self, highlighter: &'h Highlighter) -> Result<Option<&'h dyn Grammar>> {
+        match self.syntax.as_deref() {
+            Some("none") => Ok(None),
+            Some(name) => match highlighter.grammar(name) {
+                Some(grammar) => Ok(Some(grammar)),
+                None => {
+                    let names: Vec<&str> = highlighter.grammars().map(|g| g.name()).collect();
+                    bail!(
+                        "unknown syntax '{}'; try one of: {}",
+                        name,
+                        names.join(", ")
+                    )
+                }
+            },
+            None if self.interpret || !io::stdout().is_terminal() => Ok(None),
+            None => Ok(self
+                .file
+                .as_deref()
+                .and_then(|path| highlighter.grammar_for(path))),
+        }
+    }
+
+    /// `content` as frame content, interpreted if asked to.
+    fn content(&self, content: &str) -> Text {
+        if self.interpret {
+            interpret(content)
+        } else {
+            Text::from_ansi(content)
+        }
+    }
+
+    /// The frame size when not fitting to content: the arguments, else the terminal.
+    fn size(&self) -> (usize, usize) {
+        self.size_of(&ConsoleFrame::new())
+    }
+
+    /// The frame size for drawing on `console`.
+    fn size_of(&self, console: &ConsoleFrame) -> (usize, usize) {
+        let (width, height) = console.size();
+        (self.width.unwrap_or(width), self.height.unwrap_or(height))
+    }
+}
+
+pub fn run(args: Args) -> Result<()> {
+    if args.list_animations {
+        return list_animations();
+    }
+    #[cfg(feature = "pty")]
+    if let Some(command) = &args.exec {
+        return exec::run(&args, command);
+    }
+    if let Some(command) = &args.watch {
+        return watch::run(&args, command);
+    }
+    if let Some(name) = &args.animation {
+        return play::run(&args, name);
+    }
+    if let (true, Some(path)) = (args.follow, &args.file) {
+        return follow::run(&args, path);
+    }
+    let piped = match &args.file {
+        Some(path) => path.as_os_str() == "-",
+        None => !io::stdin().is_terminal(),
+    };
+    // without a terminal to draw on, stdin is shown as a single static frame
+    let interactive = !args.once && ConsoleFrame::tty().is_ok();
+    if piped && interactive {
+        return follow::run_stdin(&args);
+    }
+    let text = read_input(&args)?;
+    if piped || args.once {
+        let mut engine = engine(&args);
+        if args.height.is_none() {
+            let height = engine.layout(&text).len() + engine.framespace();
+            engine.resize(engine.config().width, height);
+        }
+        write_frame(&mut io::stdout(), &engine.compose_text(&text))
+    } else {
+        view::run(&args, text)
+    }
+}
+
+fn parse_fps(value: &str) -> Result<f32> {
+    let fps: f32 = value.parse()?;
+    if !(fps > 0.0 && fps.is_finite()) {
+        bail!("must be a positive number");
+    }
+    Ok(fps)
+}
+
+fn parse_interval(value: &str) -> Result<f32> {
+    let secs: f32 = value.parse()?;
+    if !(secs >= 0.1 && secs.is_finite()) {
+        bail!("must be at least 0.1 seconds");
+    }
+    Ok(secs)
+}
+
+/// An engine for composing frames with the configured size.
+fn engine(args: &Args) -> FrameEngine<ConsoleFrame> {
+    engine_with(args, args.frame_config())
+}
+
+/// An engine for `config`, sized by the arguments or the terminal.
+fn engine_with(args: &Args, config: FrameConfig) -> FrameEngine<ConsoleFrame> {
+    let (width, height) = args.size();
+    FrameEngine::new(
+        &config.with_width(width).with_height(height),
+        ConsoleFrame::new(),
+    )
+}
+
+fn read_input(args: &Args) -> Result<Text> {
+    let content = match &args.file {
+        Some(path) if path.as_os_str() == "-" => read_stdin()?,
+        Some(path) => std::fs::read_to_string(path)
+            .with_context(|| format!("cannot read {}", path.display()))?,
+        None if !io::stdin().is_terminal() => read_stdin()?,
+        None => bail!("nothing to show: pass a FILE, `-` for stdin, or --animation NAME"),
+    };
+    let highlighter = Highlighter::new();
+    match args.grammar(&highlighter)? {
+        Some(grammar) => Ok(highlighter.highlight(grammar, &content)),
+        None => Ok(args.content(&content)),
+    }
+}
+
+fn read_stdin() -> Result<String> {
+    let mut content = String::new();
+    io::stdin()
+        .read_to_string(&mut content)
+        .context("cannot read stdin")?;
+    Ok(content)
+}
+
+fn list_animations() -> Result<()> {
+    let mut out = io::stdout().lock();
+    let names = Animation::ALL
+        .iter()
+        .map(|a| a.name())
+        .chain(SPINNERS.iter().map(|s| s.name));
+    for name in names {
+        if let Err(err) = writeln!(out, "{}", name) {
+            return ignore_broken_pipe(err);
+        }
+    }
+    Ok(())
+}
+
+/// Write `frame` as plain lines, styled only if it has styles.
+fn write_frame(out: &mut impl Write, frame: &RenderedFrame) -> Result<()> {
+    for y in 0..frame.height() {
+        if let Err(err) = writeln!(out, "{}", frame.ansi_line(y)) {
+            return ignore_broken_pipe(err);
+        }
+    }
+    Ok(())
+}
+
+/// A closed pipe (e.g. `| head`) just means nobody wants the rest.
+fn ignore_broken_pipe(err: io::Error) -> Result<()> {
+    match err.kind() {
+        io::ErrorKind::BrokenPipe => Ok(()),
+        _ => Err(err).context("cannot write frame"),
+    }
+}
@@ -0,0 +1,89 @@
+use super::{engine_with, view, write_frame, Args};
+use anyhow::{bail, Result};
+use cli_frame::animations::{spinner, Animation};
+use cli_frame::app::{App, AppEvent, Control};
+use cli_frame::frame::{FrameConfig, Text};
+use cli_frame::input::{Action, InputEvent};
+
+/// Frame rate when neither `--fps` nor the spinner sets one.
+const DEFAULT_FPS: f32 = 10.0;
+
+/// Plays animation frames on each tick.
+struct Player {
+    frames: Vec<String>,
+    config: FrameConfig,
+    loops: Option<u64>,
+    paused: bool,
+    help: bool,
+    /// Frames advanced so far, not counting time spent paused.
+    position: u64,
+    last_tick: u64,
+}
+
+impl App for Player {
+    fn update(&mut self, event: AppEvent) -> Control {
+        match event {
+            AppEvent::Tick(tick) => {
+                if !self.paused {
+                    self.position += tick - self.last_tick;
+                }
+                self.last_tick = tick;
+                let len = self.frames.len() as u64;
+                if self.loops.is_some_and(|loops| self.position >= loops * len) {
+                    return Control::Quit;
+                }
+            }
+            AppEvent::Input(InputEvent::Action(Action::TogglePause)) => self.paused = !self.paused,
+            AppEvent::Input(InputEvent::Action(Action::ToggleHelp)) => self.help = !self.help,
+            _ => {}
+        }
+        Control::Continue
+    }
+
+    fn view(&self) -> Text {
+        if self.help {
+            return view::help();
+        }
+        let frame = &self.frames[(self.position % self.frames.len() as u64) as usize];
+        Text::raw(frame)
+    }
+
+    fn frame_config(&self) -> FrameConfig {
+        self.config.clone()
+    }
+}
+
+pub fn run(args: &Args, name: &str) -> Result<()> {
+    // animation frames aren't lines of anything, so they never get a line-number gutter
+    let config = args.frame_config().with_line_numbers(false);
+    let engine = engine_with(args, config.clone());
+    let (frames, fps) = if let Some(animation) = Animation::from_name(name) {
+        (
+            animation.frames_sized(engine.content_width(), engine.content_height()),
+            DEFAULT_FPS,
+        )
+    } else if let Some(spinner) = spinner(name) {
+        (
+            spinner.frames.iter().map(|f| f.to_string()).collect(),
+            1.0 / spinner.interval.as_secs_f32(),
+        )
+    } else {
+        bail!("unknown animation '{}'; see --list-animations", name);
+    };
+
+    if args.once {
+        return write_frame(&mut std::io::stdout(), &engine.compose(&frames[0]));
+    }
+    let mut player = Player {
+        frames,
+        config,
+        loops: args.loops,
+        paused: false,
+        help: false,
+        position: 0,
+        last_tick: 0,
+    };
+    view::runtime(args)
+        .with_fps(args.fps.unwrap_or(fps))
+        .run(&mut player)
+}
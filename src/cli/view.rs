@@ -0,0 +1,58 @@
+use super::Args;
+use anyhow::Result;
+use cli_frame::app::{App, AppEvent, Control, Runtime};
+use cli_frame::frame::{FrameConfig, Text};
+use cli_frame::input::{Action, InputEvent, Keymap};
+
+/// Scrollable view of static text.
+struct Viewer {
+    text: Text,
+    config: FrameConfig,
+    help: bool,
+}
+
+impl App for Viewer {
+    fn update(&mut self, event: AppEvent) -> Control {
+        if event == AppEvent::Input(InputEvent::Action(Action::ToggleHelp)) {
+            self.help = !self.help;
+        }
+        Control::Continue
+    }
+
+    fn view(&self) -> Text {
+        if self.help {
+            help()
+        } else {
+            self.text.clone()
+        }
+    }
+
+    fn frame_config(&self) -> FrameConfig {
+        self.config.clone()
+    }
+}
+
+/// Key bindings, shown when help is toggled.
+pub(super) fn help() -> Text {
+    Text::raw(&Keymap::default().help_lines().join("\n"))
+}
+
+pub(super) fn runtime(args: &Args) -> Runtime {
+    let runtime = Runtime::new().with_mouse(true);
+    match (args.width, args.height) {
+        (None, None) => runtime,
+        _ => {
+            let (width, height) = args.size();
+            runtime.with_size(width, height)
+        }
+    }
+}
+
+pub fn run(args: &Args, text: Text) -> Result<()> {
+    let mut viewer = Viewer {
+        text,
+        config: args.frame_config(),
+        help: false,
+    };
+    runtime(args).with_fps(20.0).run(&mut viewer)
+}
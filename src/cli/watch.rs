@@ -0,0 +1,102 @@
+use super::{view, Args};
+use anyhow::{Error, Result};
+use cli_frame::app::{App, AppEvent, Control};
+use cli_frame::frame::{FrameConfig, Style, Text};
+use cli_frame::input::{Action, InputEvent};
+use cli_frame::terminal::interpret;
+use cli_frame::watch::{highlight_changes, utc_time_of_day, RunResult, Watcher};
+use std::time::Duration;
+
+/// Shows the latest output of a periodically re-run command.
+struct Watch {
+    watcher: Watcher,
+    differences: bool,
+    interpret: bool,
+    config: FrameConfig,
+    last: Option<RunResult>,
+    text: Text,
+    help: bool,
+    error: Option<Error>,
+}
+
+impl Watch {
+    fn show(&mut self, result: RunResult) {
+        let content = |output: &str| {
+            if self.interpret {
+                interpret(output)
+            } else {
+                Text::from_ansi(output)
+            }
+        };
+        self.text = match &self.last {
+            Some(last) if self.differences => highlight_changes(
+                &content(&last.output).plain(),
+                &content(&result.output).plain(),
+                Style::new().with_reverse(true),
+            ),
+            _ => content(&result.output),
+        };
+        self.last = Some(result);
+    }
+}
+
+impl App for Watch {
+    fn update(&mut self, event: AppEvent) -> Control {
+        match event {
+            AppEvent::Tick(_) => match self.watcher.poll() {
+                Ok(Some(result)) => self.show(result),
+                Ok(None) => {}
+                Err(err) => {
+                    self.error = Some(err);
+                    return Control::Quit;
+                }
+            },
+            AppEvent::Input(InputEvent::Action(Action::ToggleHelp)) => self.help = !self.help,
+            _ => {}
+        }
+        Control::Continue
+    }
+
+    fn view(&self) -> Text {
+        if self.help {
+            view::help()
+        } else {
+            self.text.clone()
+        }
+    }
+
+    fn frame_config(&self) -> FrameConfig {
+        let every = format!("every {}s", self.watcher.interval().as_secs_f32());
+        let footer = match &self.last {
+            Some(last) => format!(
+                " {} | {} | {} ",
+                every,
+                utc_time_of_day(last.started),
+                last.status_text()
+            ),
+            None => format!(" {} | running... ", every),
+        };
+        self.config
+            .clone()
+            .with_title(&format!(" {} ", self.watcher.command()))
+            .with_footer(&footer)
+    }
+}
+
+pub fn run(args: &Args, command: &str) -> Result<()> {
+    let mut watch = Watch {
+        watcher: Watcher::spawn(command, Duration::from_secs_f32(args.interval)),
+        differences: args.differences,
+        interpret: args.interpret,
+        config: args.frame_config(),
+        last: None,
+        text: Text::default(),
+        help: false,
+        error: None,
+    };
+    view::runtime(args).with_fps(10.0).run(&mut watch)?;
+    match watch.error {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,299 @@
+//! Embedded 6x10 bitmap font used by the raster exporters.
+//!
+//! ASCII glyphs are from the X11 "misc-fixed" 6x10 font (public domain).
+//! Block elements, box drawing and braille are generated so that frame borders,
+//! shaded globes and spinners rasterize without extra font data.
+
+pub const GLYPH_WIDTH: usize = 6;
+pub const GLYPH_HEIGHT: usize = 10;
+
+/// A glyph bitmap: one byte per row, using the low `GLYPH_WIDTH` bits, MSB on the left.
+pub type Glyph = [u8; GLYPH_HEIGHT];
+
+const FULL_ROW: u8 = 0b11_1111;
+
+const ASCII: [Glyph; 95] = [
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // ' '
+    [0x00, 0x08, 0x08, 0x08, 0x08, 0x08, 0x00, 0x08, 0x00, 0x00], // '!'
+    [0x00, 0x14, 0x14, 0x14, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // '"'
+    [0x00, 0x14, 0x14, 0x3e, 0x14, 0x3e, 0x14, 0x14, 0x00, 0x00], // '#'
+    [0x00, 0x08, 0x1c, 0x28, 0x1c, 0x0a, 0x1c, 0x08, 0x00, 0x00], // '$'
+    [0x00, 0x12, 0x2a, 0x14, 0x08, 0x14, 0x2a, 0x24, 0x00, 0x00], // '%'
+    [0x00, 0x10, 0x28, 0x28, 0x10, 0x2a, 0x24, 0x1a, 0x00, 0x00], // '&'
+    [0x00, 0x08, 0x08, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // "'"
+    [0x00, 0x04, 0x08, 0x10, 0x10, 0x10, 0x08, 0x04, 0x00, 0x00], // '('
+    [0x00, 0x10, 0x08, 0x04, 0x04, 0x04, 0x08, 0x10, 0x00, 0x00], // ')'
+    [0x00, 0x00, 0x22, 0x14, 0x3e, 0x14, 0x22, 0x00, 0x00, 0x00], // '*'
+    [0x00, 0x00, 0x08, 0x08, 0x3e, 0x08, 0x08, 0x00, 0x00, 0x00], // '+'
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0c, 0x08, 0x10, 0x00], // ','
+    [0x00, 0x00, 0x00, 0x00, 0x3e, 0x00, 0x00, 0x00, 0x00, 0x00], // '-'
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x1c, 0x08, 0x00], // '.'
+    [0x00, 0x02, 0x02, 0x04, 0x08, 0x10, 0x20, 0x20, 0x00, 0x00], // '/'
+    [0x00, 0x08, 0x14, 0x22, 0x22, 0x22, 0x14, 0x08, 0x00, 0x00], // '0'
+    [0x00, 0x08, 0x18, 0x28, 0x08, 0x08, 0x08, 0x3e, 0x00, 0x00], // '1'
+    [0x00, 0x1c, 0x22, 0x02, 0x0c, 0x10, 0x20, 0x3e, 0x00, 0x00], // '2'
+    [0x00, 0x3e, 0x02, 0x04, 0x0c, 0x02, 0x22, 0x1c, 0x00, 0x00], // '3'
+    [0x00, 0x04, 0x0c, 0x14, 0x24, 0x3e, 0x04, 0x04, 0x00, 0x00], // '4'
+    [0x00, 0x3e, 0x20, 0x2c, 0x32, 0x02, 0x22, 0x1c, 0x00, 0x00], // '5'
+    [0x00, 0x0c, 0x10, 0x20, 0x2c, 0x32, 0x22, 0x1c, 0x00, 0x00], // '6'
+    [0x00, 0x3e, 0x02, 0x04, 0x04, 0x08, 0x10, 0x10, 0x00, 0x00], // '7'
+    [0x00, 0x1c, 0x22, 0x22, 0x1c, 0x22, 0x22, 0x1c, 0x00, 0x00], // '8'
+    [0x00, 0x1c, 0x22, 0x26, 0x1a, 0x02, 0x04, 0x18, 0x00, 0x00], // '9'
+    [0x00, 0x00, 0x08, 0x1c, 0x08, 0x00, 0x08, 0x1c, 0x08, 0x00], // ':'
+    [0x00, 0x00, 0x08, 0x1c, 0x08, 0x00, 0x0c, 0x08, 0x10, 0x00], // ';'
+    [0x00, 0x02, 0x04, 0x08, 0x10, 0x08, 0x04, 0x02, 0x00, 0x00], // '<'
+    [0x00, 0x00, 0x00, 0x3e, 0x00, 0x3e, 0x00, 0x00, 0x00, 0x00], // '='
+    [0x00, 0x10, 0x08, 0x04, 0x02, 0x04, 0x08, 0x10, 0x00, 0x00], // '>'
+    [0x00, 0x1c, 0x22, 0x04, 0x08, 0x08, 0x00, 0x08, 0x00, 0x00], // '?'
+    [0x00, 0x1c, 0x22, 0x26, 0x2a, 0x2c, 0x20, 0x1c, 0x00, 0x00], // '@'
+    [0x00, 0x08, 0x14, 0x22, 0x22, 0x3e, 0x22, 0x22, 0x00, 0x00], // 'A'
+    [0x00, 0x3c, 0x12, 0x12, 0x1c, 0x12, 0x12, 0x3c, 0x00, 0x00], // 'B'
+    [0x00, 0x1c, 0x22, 0x20, 0x20, 0x20, 0x22, 0x1c, 0x00, 0x00], // 'C'
+    [0x00, 0x3c, 0x12, 0x12, 0x12, 0x12, 0x12, 0x3c, 0x00, 0x00], // 'D'
+    [0x00, 0x3e, 0x20, 0x20, 0x3c, 0x20, 0x20, 0x3e, 0x00, 0x00], // 'E'
+    [0x00, 0x3e, 0x20, 0x20, 0x3c, 0x20, 0x20, 0x20, 0x00, 0x00], // 'F'
+    [0x00, 0x1c, 0x22, 0x20, 0x20, 0x26, 0x22, 0x1c, 0x00, 0x00], // 'G'
+    [0x00, 0x22, 0x22, 0x22, 0x3e, 0x22, 0x22, 0x22, 0x00, 0x00], // 'H'
+    [0x00, 0x1c, 0x08, 0x08, 0x08, 0x08, 0x08, 0x1c, 0x00, 0x00], // 'I'
+    [0x00, 0x0e, 0x04, 0x04, 0x04, 0x04, 0x24, 0x18, 0x00, 0x00], // 'J'
+    [0x00, 0x22, 0x24, 0x28, 0x30, 0x28, 0x24, 0x22, 0x00, 0x00], // 'K'
+    [0x00, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x3e, 0x00, 0x00], // 'L'
+    [0x00, 0x22, 0x22, 0x36, 0x2a, 0x22, 0x22, 0x22, 0x00, 0x00], // 'M'
+    [0x00, 0x22, 0x22, 0x32, 0x2a, 0x26, 0x22, 0x22, 0x00, 0x00], // 'N'
+    [0x00, 0x1c, 0x22, 0x22, 0x22, 0x22, 0x22, 0x1c, 0x00, 0x00], // 'O'
+    [0x00, 0x3c, 0x22, 0x22, 0x3c, 0x20, 0x20, 0x20, 0x00, 0x00], // 'P'
+    [0x00, 0x1c, 0x22, 0x22, 0x22, 0x22, 0x2a, 0x1c, 0x02, 0x00], // 'Q'
+    [0x00, 0x3c, 0x22, 0x22, 0x3c, 0x28, 0x24, 0x22, 0x00, 0x00], // 'R'
+    [0x00, 0x1c, 0x22, 0x20, 0x1c, 0x02, 0x22, 0x1c, 0x00, 0x00], // 'S'
+    [0x00, 0x3e, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x00, 0x00], // 'T'
+    [0x00, 0x22, 0x22, 0x22, 0x22, 0x22, 0x22, 0x1c, 0x00, 0x00], // 'U'
+    [0x00, 0x22, 0x22, 0x22, 0x14, 0x14, 0x14, 0x08, 0x00, 0x00], // 'V'
+    [0x00, 0x22, 0x22, 0x22, 0x2a, 0x2a, 0x36, 0x22, 0x00, 0x00], // 'W'
+    [0x00, 0x22, 0x22, 0x14, 0x08, 0x14, 0x22, 0x22, 0x00, 0x00], // 'X'
+    [0x00, 0x22, 0x22, 0x14, 0x08, 0x08, 0x08, 0x08, 0x00, 0x00], // 'Y'
+    [0x00, 0x3e, 0x02, 0x04, 0x08, 0x10, 0x20, 0x3e, 0x00, 0x00], // 'Z'
+    [0x00, 0x1c, 0x10, 0x10, 0x10, 0x10, 0x10, 0x1c, 0x00, 0x00], // '['
+    [0x00, 0x20, 0x20, 0x10, 0x08, 0x04, 0x02, 0x02, 0x00, 0x00], // '\\'
+    [0x00, 0x1c, 0x04, 0x04, 0x04, 0x04, 0x04, 0x1c, 0x00, 0x00], // ']'
+    [0x00, 0x08, 0x14, 0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // '^'
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3e, 0x00], // '_'
+    [0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // '`'
+    [0x00, 0x00, 0x00, 0x1c, 0x02, 0x1e, 0x22, 0x1e, 0x00, 0x00], // 'a'
+    [0x00, 0x20, 0x20, 0x2c, 0x32, 0x22, 0x32, 0x2c, 0x00, 0x00], // 'b'
+    [0x00, 0x00, 0x00, 0x1c, 0x22, 0x20, 0x22, 0x1c, 0x00, 0x00], // 'c'
+    [0x00, 0x02, 0x02, 0x1a, 0x26, 0x22, 0x26, 0x1a, 0x00, 0x00], // 'd'
+    [0x00, 0x00, 0x00, 0x1c, 0x22, 0x3e, 0x20, 0x1c, 0x00, 0x00], // 'e'
+    [0x00, 0x0c, 0x12, 0x10, 0x3c, 0x10, 0x10, 0x10, 0x00, 0x00], // 'f'
+    [0x00, 0x00, 0x00, 0x1e, 0x22, 0x22, 0x1e, 0x02, 0x22, 0x1c], // 'g'
+    [0x00, 0x20, 0x20, 0x2c, 0x32, 0x22, 0x22, 0x22, 0x00, 0x00], // 'h'
+    [0x00, 0x08, 0x00, 0x18, 0x08, 0x08, 0x08, 0x1c, 0x00, 0x00], // 'i'
+    [0x00, 0x02, 0x00, 0x06, 0x02, 0x02, 0x02, 0x12, 0x12, 0x0c], // 'j'
+    [0x00, 0x20, 0x20, 0x22, 0x24, 0x38, 0x24, 0x22, 0x00, 0x00], // 'k'
+    [0x00, 0x18, 0x08, 0x08, 0x08, 0x08, 0x08, 0x1c, 0x00, 0x00], // 'l'
+    [0x00, 0x00, 0x00, 0x34, 0x2a, 0x2a, 0x2a, 0x22, 0x00, 0x00], // 'm'
+    [0x00, 0x00, 0x00, 0x2c, 0x32, 0x22, 0x22, 0x22, 0x00, 0x00], // 'n'
+    [0x00, 0x00, 0x00, 0x1c, 0x22, 0x22, 0x22, 0x1c, 0x00, 0x00], // 'o'
+    [0x00, 0x00, 0x00, 0x2c, 0x32, 0x22, 0x32, 0x2c, 0x20, 0x20], // 'p'
+    [0x00, 0x00, 0x00, 0x1a, 0x26, 0x22, 0x26, 0x1a, 0x02, 0x02], // 'q'
+    [0x00, 0x00, 0x00, 0x2c, 0x32, 0x20, 0x20, 0x20, 0x00, 0x00], // 'r'
+    [0x00, 0x00, 0x00, 0x1c, 0x20, 0x1c, 0x02, 0x3c, 0x00, 0x00], // 's'
+    [0x00, 0x10, 0x10, 0x3c, 0x10, 0x10, 0x12, 0x0c, 0x00, 0x00], // 't'
+    [0x00, 0x00, 0x00, 0x22, 0x22, 0x22, 0x26, 0x1a, 0x00, 0x00], // 'u'
+    [0x00, 0x00, 0x00, 0x22, 0x22, 0x14, 0x14, 0x08, 0x00, 0x00], // 'v'
+    [0x00, 0x00, 0x00, 0x22, 0x22, 0x2a, 0x2a, 0x14, 0x00, 0x00], // 'w'
+    [0x00, 0x00, 0x00, 0x22, 0x14, 0x08, 0x14, 0x22, 0x00, 0x00], // 'x'
+    [0x00, 0x00, 0x00, 0x22, 0x22, 0x26, 0x1a, 0x02, 0x22, 0x1c], // 'y'
+    [0x00, 0x00, 0x00, 0x3e, 0x04, 0x08, 0x10, 0x3e, 0x00, 0x00], // 'z'
+    [0x00, 0x06, 0x08, 0x04, 0x18, 0x04, 0x08, 0x06, 0x00, 0x00], // '{'
+    [0x00, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x00, 0x00], // '|'
+    [0x00, 0x18, 0x04, 0x08, 0x06, 0x08, 0x04, 0x18, 0x00, 0x00], // '}'
+    [0x00, 0x12, 0x2a, 0x24, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // '~'
+];
+
+/// Bitmap for `ch`; characters without a glyph are drawn as an outlined box.
+pub fn glyph(ch: char) -> Glyph {
+    match ch as u32 {
+        0x20..=0x7e => ASCII[ch as usize - 0x20],
+        0x2500..=0x257f => box_drawing(ch).unwrap_or_else(missing),
+        0x2580..=0x259f => block(ch).unwrap_or_else(missing),
+        0x2800..=0x28ff => braille(ch as u32 - 0x2800),
+        _ if ch.is_whitespace() => [0; GLYPH_HEIGHT],
+        _ => missing(),
+    }
+}
+
+fn missing() -> Glyph {
+    let mut g = [0b10_0010; GLYPH_HEIGHT];
+    g[1] = 0b11_1110;
+    g[8] = 0b11_1110;
+    g[0] = 0;
+    g[9] = 0;
+    g
+}
+
+/// Rows `from..to` filled with `row`.
+fn fill(from: usize, to: usize, row: u8) -> Glyph {
+    let mut g = [0; GLYPH_HEIGHT];
+    for r in g.iter_mut().take(to).skip(from) {
+        *r = row;
+    }
+    g
+}
+
+/// Checkerboard-style shading, `level` in 1..=3 for light, medium and dark shade.
+fn shade(level: u8) -> Glyph {
+    let mut g = [0; GLYPH_HEIGHT];
+    for (y, r) in g.iter_mut().enumerate() {
+        let (even, odd) = match level {
+            1 => (0b10_0010, 0b00_1000),
+            2 => (0b10_1010, 0b01_0101),
+            _ => (0b11_1011, 0b10_1110),
+        };
+        *r = if y % 2 == 0 { even } else { odd };
+    }
+    g
+}
+
+fn block(ch: char) -> Option<Glyph> {
+    let h = GLYPH_HEIGHT;
+    let left = |cols: usize| FULL_ROW & !(FULL_ROW >> cols);
+    Some(match ch {
+        '▀' => fill(0, h / 2, FULL_ROW),
+        '▁'..='█' => {
+            // lower eighths
+            let eighths = ch as usize - '▀' as usize;
+            fill(h - (h * eighths + 4) / 8, h, FULL_ROW)
+        }
+        '▉'..='▏' => {
+            // left eighths, from seven eighths down to one
+            let eighths = 8 - (ch as usize - '█' as usize);
+            fill(0, h, left((GLYPH_WIDTH * eighths + 4) / 8))
+        }
+        '▐' => fill(0, h, FULL_ROW >> (GLYPH_WIDTH / 2)),
+        '░' => shade(1),
+        '▒' => shade(2),
+        '▓' => shade(3),
+        '▔' => fill(0, 1, FULL_ROW),
+        '▕' => fill(0, h, 0b00_0001),
+        _ => {
+            // quadrants
+            let (tl, tr, bl, br) = match ch {
+                '▖' => (false, false, true, false),
+                '▗' => (false, false, false, true),
+                '▘' => (true, false, false, false),
+                '▙' => (true, false, true, true),
+                '▚' => (true, false, false, true),
+                '▛' => (true, true, true, false),
+                '▜' => (true, true, false, true),
+                '▝' => (false, true, false, false),
+                '▞' => (false, true, true, false),
+                '▟' => (false, true, true, true),
+                _ => return None,
+            };
+            let half = |l: bool, r: bool| {
+                (if l { left(GLYPH_WIDTH / 2) } else { 0 })
+                    | (if r { FULL_ROW >> (GLYPH_WIDTH / 2) } else { 0 })
+            };
+            let mut g = fill(0, h / 2, half(tl, tr));
+            for r in g.iter_mut().skip(h / 2) {
+                *r = half(bl, br);
+            }
+            g
+        }
+    })
+}
+
+/// Light/heavy/double box drawing lines, all drawn as single-pixel strokes.
+fn box_drawing(ch: char) -> Option<Glyph> {
+    // (up, down, left, right)
+    let arms = match ch {
+        '─' | '━' | '═' | '╌' | '┄' | '┈' => (false, false, true, true),
+        '│' | '┃' | '║' | '╎' | '┆' | '┊' => (true, true, false, false),
+        '┌' | '┏' | '╔' | '╭' => (false, true, false, true),
+        '┐' | '┓' | '╗' | '╮' => (false, true, true, false),
+        '└' | '┗' | '╚' | '╰' => (true, false, false, true),
+        '┘' | '┛' | '╝' | '╯' => (true, false, true, false),
+        '├' | '┣' | '╠' => (true, true, false, true),
+        '┤' | '┫' | '╣' => (true, true, true, false),
+        '┬' | '┳' | '╦' => (false, true, true, true),
+        '┴' | '┻' | '╩' => (true, false, true, true),
+        '┼' | '╋' | '╬' => (true, true, true, true),
+        _ => return None,
+    };
+    let (up, down, left, right) = arms;
+    let (cx, cy) = (GLYPH_WIDTH / 2 - 1, GLYPH_HEIGHT / 2 - 1);
+    let center_bit = 1 << (GLYPH_WIDTH - 1 - cx);
+    let mut g = [0; GLYPH_HEIGHT];
+    for (y, r) in g.iter_mut().enumerate() {
+        if (up && y <= cy) || (down && y >= cy) {
+            *r |= center_bit;
+        }
+    }
+    if left {
+        g[cy] |= FULL_ROW & !(center_bit - 1);
+    }
+    if right {
+        g[cy] |= (center_bit << 1) - 1;
+    }
+    Some(g)
+}
+
+/// Braille pattern: bits 0-2 and 6 are the left column top to bottom, 3-5 and 7 the right.
+fn braille(bits: u32) -> Glyph {
+    const DOTS: [(usize, usize); 8] = [
+        (0, 0),
+        (0, 1),
+        (0, 2),
+        (1, 0),
+        (1, 1),
+        (1, 2),
+        (0, 3),
+        (1, 3),
+    ];
+    let mut g = [0; GLYPH_HEIGHT];
+    for (i, (col, row)) in DOTS.iter().enumerate() {
+        if bits & (1 << i) != 0 {
+            let y = 1 + row * 2;
+            g[y] |= if *col == 0 { 0b01_0000 } else { 0b00_0100 };
+        }
+    }
+    g
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ascii_comes_from_the_table() {
+        assert_eq!(
+            glyph('!'),
+            [0x00, 0x08, 0x08, 0x08, 0x08, 0x08, 0x00, 0x08, 0x00, 0x00]
+        );
+        assert_eq!(glyph(' '), [0; GLYPH_HEIGHT]);
+        assert_eq!(glyph('\t'), [0; GLYPH_HEIGHT]);
+    }
+
+    #[test]
+    fn generated_blocks_lines_and_braille() {
+        assert_eq!(glyph('█'), [FULL_ROW; GLYPH_HEIGHT]);
+        assert_eq!(glyph('▄'), fill(5, GLYPH_HEIGHT, FULL_ROW));
+        assert_eq!(glyph('▌'), [0b11_1000; GLYPH_HEIGHT]);
+        assert_eq!(glyph('─'), fill(4, 5, FULL_ROW));
+        assert_eq!(glyph('│'), [0b00_1000; GLYPH_HEIGHT]);
+        assert_eq!(glyph('⠁'), fill(1, 2, 0b01_0000));
+        let all = glyph('⣿');
+        for (y, row) in all.iter().enumerate() {
+            assert_eq!(*row, if y % 2 == 1 && y < 8 { 0b01_0100 } else { 0 });
+        }
+    }
+
+    #[test]
+    fn unknown_characters_are_boxes() {
+        assert_eq!(glyph('€'), missing());
+        assert_eq!(
+            missing(),
+            [0x00, 0x3e, 0x22, 0x22, 0x22, 0x22, 0x22, 0x22, 0x3e, 0x00]
+        );
+    }
+}
@@ -0,0 +1,221 @@
+use super::font::{glyph, GLYPH_HEIGHT, GLYPH_WIDTH};
+use super::{extent, ExportOptions};
+use crate::frame::style::{to_rgb, Color};
+use crate::frame::RenderedFrame;
+use ::gif::{Encoder, Frame, Repeat};
+use std::collections::HashMap;
+use std::io::Write;
+use std::time::Duration;
+
+type Rgb = (u8, u8, u8);
+
+/// Write a single frame as a still GIF image.
+pub fn frame<W: Write>(
+    writer: W,
+    frame: &RenderedFrame,
+    options: &ExportOptions,
+) -> anyhow::Result<()> {
+    animation_with_delays(writer, &[(frame.clone(), Duration::ZERO)], options)
+}
+
+/// Write `frames` as a looping animated GIF, showing each one for `delay`.
+pub fn animation<W: Write>(
+    writer: W,
+    frames: &[RenderedFrame],
+    delay: Duration,
+    options: &ExportOptions,
+) -> anyhow::Result<()> {
+    let frames = frames
+        .iter()
+        .map(|f| (f.clone(), delay))
+        .collect::<Vec<_>>();
+    animation_with_delays(writer, &frames, options)
+}
+
+/// Write a looping animated GIF where every frame carries its own delay.
+///
+/// GIF delays have centisecond resolution; shorter delays are rounded.
+pub fn animation_with_delays<W: Write>(
+    writer: W,
+    frames: &[(RenderedFrame, Duration)],
+    options: &ExportOptions,
+) -> anyhow::Result<()> {
+    let (cols, rows) = extent(frames.iter().map(|(f, _)| f));
+    anyhow::ensure!(
+        cols > 0 && rows > 0,
+        "nothing to export: no frames or only empty ones"
+    );
+    let scale = options.scale.max(1);
+    let width = u16::try_from(cols * GLYPH_WIDTH * scale)?;
+    let height = u16::try_from(rows * GLYPH_HEIGHT * scale)?;
+
+    let images = frames
+        .iter()
+        .map(|(f, _)| rasterize(f, cols, rows, options))
+        .collect::<Vec<_>>();
+
+    // a shared palette keeps the file small; fall back to per-frame quantization if needed
+    let mut palette: HashMap<Rgb, u8> = HashMap::new();
+    for px in images.iter().flatten() {
+        if palette.len() > 256 {
+            break;
+        }
+        let next = palette.len() as u8;
+        palette.entry(*px).or_insert(next);
+    }
+    let fits = palette.len() <= 256;
+    let mut global = vec![0u8; palette.len() * 3];
+    if fits {
+        for (rgb, idx) in &palette {
+            let i = *idx as usize * 3;
+            global[i..i + 3].copy_from_slice(&[rgb.0, rgb.1, rgb.2]);
+        }
+    }
+
+    let mut encoder = Encoder::new(writer, width, height, if fits { &global } else { &[] })?;
+    encoder.set_repeat(Repeat::Infinite)?;
+    for (image, (_, delay)) in images.iter().zip(frames) {
+        let mut gif_frame = if fits {
+            Frame {
+                width,
+                height,
+                buffer: image
+                    .iter()
+                    .map(|px| palette[px])
+                    .collect::<Vec<u8>>()
+                    .into(),
+                ..Frame::default()
+            }
+        } else {
+            let rgb = image
+                .iter()
+                .flat_map(|(r, g, b)| [*r, *g, *b])
+                .collect::<Vec<u8>>();
+            Frame::from_rgb_speed(width, height, &rgb, 10)
+        };
+        gif_frame.delay = (delay.as_millis() / 10).min(u16::MAX as u128) as u16;
+        encoder.write_frame(&gif_frame)?;
+    }
+    Ok(())
+}
+
+/// Draw `frame` onto a `cols` x `rows` cell canvas, returning row-major pixels.
+fn rasterize(frame: &RenderedFrame, cols: usize, rows: usize, options: &ExportOptions) -> Vec<Rgb> {
+    let scale = options.scale.max(1);
+    let (cell_w, cell_h) = (GLYPH_WIDTH * scale, GLYPH_HEIGHT * scale);
+    let width = cols * cell_w;
+    let mut pixels = vec![options.background; width * rows * cell_h];
+    for (y, row) in frame.rows().iter().enumerate() {
+        for (x, cell) in row.iter().enumerate() {
+            let style = cell.style;
+            let resolve = |c: Option<Color>, default: Rgb| c.and_then(to_rgb).unwrap_or(default);
+            let mut fg = resolve(style.fg, options.foreground);
+            let mut bg = resolve(style.bg, options.background);
+            if style.reverse {
+                std::mem::swap(&mut fg, &mut bg);
+            }
+            if style.dim {
+                fg = blend(fg, bg);
+            }
+            let mut bitmap = glyph(cell.ch);
+            if style.bold {
+                for r in bitmap.iter_mut() {
+                    *r |= *r >> 1;
+                }
+            }
+            if style.underline {
+                bitmap[GLYPH_HEIGHT - 1] = 0b11_1111;
+            }
+            for (gy, bits) in bitmap.iter().enumerate() {
+                for gx in 0..GLYPH_WIDTH {
+                    let on = bits & (1 << (GLYPH_WIDTH - 1 - gx)) != 0;
+                    let color = if on { fg } else { bg };
+                    for sy in 0..scale {
+                        let py = y * cell_h + gy * scale + sy;
+                        let start = py * width + x * cell_w + gx * scale;
+                        pixels[start..start + scale].fill(color);
+                    }
+                }
+            }
+        }
+    }
+    pixels
+}
+
+fn blend(a: Rgb, b: Rgb) -> Rgb {
+    let mix = |x: u8, y: u8| ((x as u16 + y as u16) / 2) as u8;
+    (mix(a.0, b.0), mix(a.1, b.1), mix(a.2, b.2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frame::style::Style;
+    use crate::frame::Cell;
+
+    /// A decoded GIF: its size, global palette and each frame's delay and color indices.
+    struct Decoded {
+        size: (u16, u16),
+        palette: Vec<u8>,
+        frames: Vec<(u16, Vec<u8>)>,
+    }
+
+    fn decode(bytes: &[u8]) -> Decoded {
+        let mut options = ::gif::DecodeOptions::new();
+        options.set_color_output(::gif::ColorOutput::Indexed);
+        let mut decoder = options.read_info(bytes).unwrap();
+        let size = (decoder.width(), decoder.height());
+        let palette = decoder.global_palette().unwrap_or_default().to_vec();
+        let mut frames = vec![];
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            frames.push((frame.delay, frame.buffer.to_vec()));
+        }
+        Decoded {
+            size,
+            palette,
+            frames,
+        }
+    }
+
+    #[test]
+    fn empty_input_is_an_error() {
+        let options = ExportOptions::new();
+        assert!(animation(vec![], &[], Duration::from_millis(100), &options).is_err());
+        let empty = RenderedFrame::new(0, 0, Cell::new(' ', Style::default()));
+        assert!(frame(vec![], &empty, &options).is_err());
+    }
+
+    #[test]
+    fn shared_palette_and_per_frame_delays() {
+        let options = ExportOptions::new()
+            .with_scale(1)
+            .with_foreground((255, 255, 255))
+            .with_background((0, 0, 0));
+        let blank = RenderedFrame::new(2, 1, Cell::new(' ', Style::default()));
+        let mut hash = blank.clone();
+        hash.set(1, 0, Cell::new('#', Style::default()));
+        let mut out = vec![];
+        animation_with_delays(
+            &mut out,
+            &[
+                (blank, Duration::from_millis(250)),
+                (hash, Duration::from_secs(1)),
+            ],
+            &options,
+        )
+        .unwrap();
+
+        let Decoded {
+            size,
+            palette,
+            frames,
+        } = decode(&out);
+        assert_eq!(size, (12, 10));
+        let colors: Vec<&[u8]> = palette.chunks(3).collect();
+        assert_eq!(&colors[..2], &[&[0, 0, 0][..], &[255, 255, 255][..]]);
+        assert_eq!(frames.len(), 2);
+        assert_eq!((frames[0].0, frames[1].0), (25, 100));
+        assert!(frames[0].1.iter().all(|&i| i == 0));
+        assert!(frames[1].1.contains(&1));
+    }
+}
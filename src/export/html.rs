@@ -0,0 +1,137 @@
+use super::{escape, keyframes_css, ExportOptions};
+use crate::frame::style::hex;
+use crate::frame::RenderedFrame;
+use std::time::Duration;
+
+/// Render a single frame as a standalone HTML document.
+pub fn frame(frame: &RenderedFrame, options: &ExportOptions) -> String {
+    document(options, "", &pre(frame, options, "frame"))
+}
+
+/// Render an animation as a standalone HTML document that loops through `frames`,
+/// showing each one for `delay`, using CSS keyframes.
+pub fn animation(frames: &[RenderedFrame], delay: Duration, options: &ExportOptions) -> String {
+    let body = frames
+        .iter()
+        .enumerate()
+        .map(|(i, f)| pre(f, options, &format!("frame f f{}", i)))
+        .collect::<String>();
+    let css = format!(
+        ".stack {{ display: grid; }}\n.stack > pre {{ grid-area: 1 / 1; }}\n{}",
+        keyframes_css(frames.len(), delay)
+    );
+    document(
+        options,
+        &css,
+        &format!("<div class=\"stack\">\n{}</div>\n", body),
+    )
+}
+
+fn document(options: &ExportOptions, css: &str, body: &str) -> String {
+    let title = options.title.as_deref().unwrap_or("cli-frame");
+    format!(
+        "<!DOCTYPE html>\n\
+         <html>\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n<style>\n\
+         body {{ margin: 0; background: {bg}; }}\n\
+         pre.frame {{ margin: 0; font-family: {font}; font-size: {size}px; line-height: {lh}; color: {fg}; background: {bg}; }}\n\
+         {css}</style>\n</head>\n<body>\n{body}</body>\n</html>\n",
+        title = escape(title),
+        font = options.font_family,
+        size = options.font_size,
+        lh = options.line_height,
+        fg = hex(options.foreground),
+        bg = hex(options.background),
+    )
+}
+
+/// One `<pre>` with a `<span>` per styled run.
+fn pre(frame: &RenderedFrame, options: &ExportOptions, class: &str) -> String {
+    let mut out = format!("<pre class=\"{}\">", class);
+    for y in 0..frame.height() {
+        if y > 0 {
+            out.push('\n');
+        }
+        for (style, text) in frame.spans(y) {
+            if style.is_plain() {
+                out.push_str(&escape(&text));
+                continue;
+            }
+            let (fg, bg) = options.colors(&style);
+            let mut css = format!("color:{}", fg);
+            if let Some(bg) = bg {
+                css.push_str(&format!(";background:{}", bg));
+            }
+            if style.bold {
+                css.push_str(";font-weight:bold");
+            }
+            if style.italic {
+                css.push_str(";font-style:italic");
+            }
+            if style.underline {
+                css.push_str(";text-decoration:underline");
+            }
+            if style.dim {
+                css.push_str(";opacity:0.6");
+            }
+            out.push_str(&format!("<span style=\"{}\">{}</span>", css, escape(&text)));
+        }
+    }
+    out.push_str("</pre>\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frame::style::{Color, Style};
+    use crate::frame::Cell;
+
+    /// `<&` over ` x`, with a bold red `&` and an `x` on blue.
+    fn sample() -> RenderedFrame {
+        let mut frame = RenderedFrame::new(2, 2, Cell::new(' ', Style::default()));
+        frame.set(0, 0, Cell::new('<', Style::default()));
+        let red = Style::new()
+            .with_fg(Color::Rgb { r: 255, g: 0, b: 0 })
+            .with_bold(true);
+        frame.set(1, 0, Cell::new('&', red));
+        let on_blue = Style::new().with_bg(Color::Rgb { r: 0, g: 0, b: 255 });
+        frame.set(1, 1, Cell::new('x', on_blue));
+        frame
+    }
+
+    #[test]
+    fn frame_matches_golden_document() {
+        let options = ExportOptions::new().with_title("a \"b\"");
+        let expected = "<!DOCTYPE html>\n\
+         <html>\n\
+         <head>\n\
+         <meta charset=\"utf-8\">\n\
+         <title>a &quot;b&quot;</title>\n\
+         <style>\n\
+         body { margin: 0; background: #181818; }\n\
+         pre.frame { margin: 0; font-family: ui-monospace, Menlo, Consolas, 'DejaVu Sans Mono', monospace; font-size: 14px; line-height: 1.2; color: #d0d0d0; background: #181818; }\n\
+         </style>\n\
+         </head>\n\
+         <body>\n\
+         <pre class=\"frame\">&lt;<span style=\"color:#ff0000;font-weight:bold\">&amp;</span>\n\
+         \x20<span style=\"color:#d0d0d0;background:#0000ff\">x</span></pre>\n\
+         </body>\n\
+         </html>\n";
+        assert_eq!(frame(&sample(), &options), expected);
+    }
+
+    #[test]
+    fn animation_stacks_frames_with_staggered_delays() {
+        let frames = [
+            sample(),
+            RenderedFrame::new(1, 1, Cell::new('z', Style::default())),
+        ];
+        let html = animation(&frames, Duration::from_millis(250), &ExportOptions::new());
+        assert!(
+            html.contains(".f { visibility: hidden; animation: cf 0.500s step-end infinite; }\n")
+        );
+        assert!(html.contains(".f1 { animation-delay: 0.250s; }\n"));
+        assert!(html.contains("<div class=\"stack\">\n<pre class=\"frame f f0\">&lt;"));
+        assert!(html.contains("<pre class=\"frame f f1\">z</pre>\n</div>\n"));
+    }
+}
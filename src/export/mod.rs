@@ -0,0 +1,144 @@
+//! Export rendered frames and animations to standalone documents.
+
+mod font;
+pub mod gif;
+pub mod html;
+pub mod svg;
+
+use crate::frame::style::{hex, to_rgb, Color, Style};
+use crate::frame::RenderedFrame;
+use std::time::Duration;
+
+/// Layout and color options shared by the document exporters.
+#[derive(Clone, Debug)]
+pub struct ExportOptions {
+    pub font_family: String,
+    /// Font size in pixels.
+    pub font_size: f32,
+    /// Width of one cell as a fraction of the font size.
+    pub cell_width: f32,
+    /// Height of one cell as a fraction of the font size.
+    pub line_height: f32,
+    /// Text color for cells without a foreground color.
+    pub foreground: (u8, u8, u8),
+    /// Page color for cells without a background color.
+    pub background: (u8, u8, u8),
+    pub title: Option<String>,
+    /// Size of one font pixel in image pixels, for raster exporters.
+    pub scale: usize,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self {
+            font_family: "ui-monospace, Menlo, Consolas, 'DejaVu Sans Mono', monospace".to_owned(),
+            font_size: 14.0,
+            cell_width: 0.6,
+            line_height: 1.2,
+            foreground: (208, 208, 208),
+            background: (24, 24, 24),
+            title: None,
+            scale: 2,
+        }
+    }
+}
+
+impl ExportOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_font_family(mut self, font_family: &str) -> Self {
+        self.font_family = font_family.to_owned();
+        self
+    }
+
+    pub fn with_font_size(mut self, font_size: f32) -> Self {
+        self.font_size = font_size;
+        self
+    }
+
+    pub fn with_foreground(mut self, foreground: (u8, u8, u8)) -> Self {
+        self.foreground = foreground;
+        self
+    }
+
+    pub fn with_background(mut self, background: (u8, u8, u8)) -> Self {
+        self.background = background;
+        self
+    }
+
+    pub fn with_title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_owned());
+        self
+    }
+
+    pub fn with_scale(mut self, scale: usize) -> Self {
+        self.scale = scale.max(1);
+        self
+    }
+
+    fn cell_px(&self) -> (f32, f32) {
+        (
+            self.font_size * self.cell_width,
+            self.font_size * self.line_height,
+        )
+    }
+
+    /// Resolved (foreground, background) colors of a style, with `reverse` applied.
+    fn colors(&self, style: &Style) -> (String, Option<String>) {
+        let resolve = |c: Option<Color>| c.and_then(to_rgb);
+        let fg = resolve(style.fg);
+        let bg = resolve(style.bg);
+        if style.reverse {
+            (
+                hex(bg.unwrap_or(self.background)),
+                Some(hex(fg.unwrap_or(self.foreground))),
+            )
+        } else {
+            (hex(fg.unwrap_or(self.foreground)), bg.map(hex))
+        }
+    }
+}
+
+/// Escape text for use in XML/HTML content and attribute values.
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// CSS that shows each `.f<i>` element for one `delay` slot out of `frames.len()`, looping.
+fn keyframes_css(frame_count: usize, delay: Duration) -> String {
+    let total = delay.as_secs_f64() * frame_count as f64;
+    let visible_pct = 100.0 / frame_count.max(1) as f64;
+    let mut css = format!(
+        "@keyframes cf {{ 0% {{ visibility: visible; }} {:.4}% {{ visibility: hidden; }} 100% {{ visibility: hidden; }} }}\n\
+         .f {{ visibility: hidden; animation: cf {:.3}s step-end infinite; }}\n",
+        visible_pct, total
+    );
+    for i in 0..frame_count {
+        css.push_str(&format!(
+            ".f{} {{ animation-delay: {:.3}s; }}\n",
+            i,
+            delay.as_secs_f64() * i as f64
+        ));
+    }
+    css
+}
+
+/// Size in cells of the largest frame in `frames`.
+fn extent<'a>(frames: impl IntoIterator<Item = &'a RenderedFrame>) -> (usize, usize) {
+    frames
+        .into_iter()
+        .fold((0, 0), |(w, h), f| (w.max(f.width()), h.max(f.height())))
+}
@@ -0,0 +1,156 @@
+use super::{escape, extent, keyframes_css, ExportOptions};
+use crate::frame::style::hex;
+use crate::frame::RenderedFrame;
+use std::time::Duration;
+
+/// Render a single frame as a standalone SVG document.
+pub fn frame(frame: &RenderedFrame, options: &ExportOptions) -> String {
+    document(
+        frame.width(),
+        frame.height(),
+        options,
+        "",
+        &group(frame, options),
+    )
+}
+
+/// Render an animation as a standalone SVG document that loops through `frames`,
+/// showing each one for `delay`, using CSS keyframes.
+pub fn animation(frames: &[RenderedFrame], delay: Duration, options: &ExportOptions) -> String {
+    let (width, height) = extent(frames);
+    let body = frames
+        .iter()
+        .enumerate()
+        .map(|(i, f)| format!("<g class=\"f f{}\">\n{}</g>\n", i, group(f, options)))
+        .collect::<String>();
+    document(
+        width,
+        height,
+        options,
+        &keyframes_css(frames.len(), delay),
+        &body,
+    )
+}
+
+fn document(width: usize, height: usize, options: &ExportOptions, css: &str, body: &str) -> String {
+    let (cw, ch) = options.cell_px();
+    let (w, h) = (width as f32 * cw, height as f32 * ch);
+    let title = options
+        .title
+        .as_ref()
+        .map(|t| format!("<title>{}</title>\n", escape(t)))
+        .unwrap_or_default();
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w:.1}\" height=\"{h:.1}\" viewBox=\"0 0 {w:.1} {h:.1}\">\n\
+         {title}<style>\n\
+         text {{ font-family: {font}; font-size: {size}px; white-space: pre; dominant-baseline: central; }}\n\
+         {css}</style>\n\
+         <rect width=\"100%\" height=\"100%\" fill=\"{bg}\"/>\n\
+         {body}</svg>\n",
+        font = options.font_family,
+        size = options.font_size,
+        bg = hex(options.background),
+    )
+}
+
+/// Background rects followed by one `<text>` per styled run, positioned on the cell grid.
+fn group(frame: &RenderedFrame, options: &ExportOptions) -> String {
+    let (cw, ch) = options.cell_px();
+    let mut rects = String::new();
+    let mut texts = String::new();
+    for y in 0..frame.height() {
+        let mut col = 0;
+        for (style, text) in frame.spans(y) {
+            let len = text.chars().count();
+            let (x, top) = (col as f32 * cw, y as f32 * ch);
+            let (fg, bg) = options.colors(&style);
+            if let Some(bg) = bg {
+                rects.push_str(&format!(
+                    "<rect x=\"{:.1}\" y=\"{:.1}\" width=\"{:.1}\" height=\"{:.1}\" fill=\"{}\"/>\n",
+                    x,
+                    top,
+                    len as f32 * cw,
+                    ch,
+                    bg
+                ));
+            }
+            if !text.trim().is_empty() {
+                let mut attrs = format!("fill=\"{}\"", fg);
+                if style.bold {
+                    attrs.push_str(" font-weight=\"bold\"");
+                }
+                if style.italic {
+                    attrs.push_str(" font-style=\"italic\"");
+                }
+                if style.underline {
+                    attrs.push_str(" text-decoration=\"underline\"");
+                }
+                if style.dim {
+                    attrs.push_str(" opacity=\"0.6\"");
+                }
+                texts.push_str(&format!(
+                    "<text x=\"{:.1}\" y=\"{:.1}\" textLength=\"{:.1}\" lengthAdjust=\"spacingAndGlyphs\" {}>{}</text>\n",
+                    x,
+                    top + ch / 2.0,
+                    len as f32 * cw,
+                    attrs,
+                    escape(&text)
+                ));
+            }
+            col += len;
+        }
+    }
+    rects + &texts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frame::style::{Color, Style};
+    use crate::frame::Cell;
+
+    /// `<&` over ` x`, with a bold red `&` and an `x` on blue.
+    fn sample() -> RenderedFrame {
+        let mut frame = RenderedFrame::new(2, 2, Cell::new(' ', Style::default()));
+        frame.set(0, 0, Cell::new('<', Style::default()));
+        let red = Style::new()
+            .with_fg(Color::Rgb { r: 255, g: 0, b: 0 })
+            .with_bold(true);
+        frame.set(1, 0, Cell::new('&', red));
+        let on_blue = Style::new().with_bg(Color::Rgb { r: 0, g: 0, b: 255 });
+        frame.set(1, 1, Cell::new('x', on_blue));
+        frame
+    }
+
+    #[test]
+    fn frame_matches_golden_document() {
+        let options = ExportOptions::new().with_title("a \"b\"");
+        let expected = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <svg xmlns=\"http://www.w3.org/2000/svg\" width=\"16.8\" height=\"33.6\" viewBox=\"0 0 16.8 33.6\">\n\
+         <title>a &quot;b&quot;</title>\n\
+         <style>\n\
+         text { font-family: ui-monospace, Menlo, Consolas, 'DejaVu Sans Mono', monospace; font-size: 14px; white-space: pre; dominant-baseline: central; }\n\
+         </style>\n\
+         <rect width=\"100%\" height=\"100%\" fill=\"#181818\"/>\n\
+         <rect x=\"8.4\" y=\"16.8\" width=\"8.4\" height=\"16.8\" fill=\"#0000ff\"/>\n\
+         <text x=\"0.0\" y=\"8.4\" textLength=\"8.4\" lengthAdjust=\"spacingAndGlyphs\" fill=\"#d0d0d0\">&lt;</text>\n\
+         <text x=\"8.4\" y=\"8.4\" textLength=\"8.4\" lengthAdjust=\"spacingAndGlyphs\" fill=\"#ff0000\" font-weight=\"bold\">&amp;</text>\n\
+         <text x=\"8.4\" y=\"25.2\" textLength=\"8.4\" lengthAdjust=\"spacingAndGlyphs\" fill=\"#d0d0d0\">x</text>\n\
+         </svg>\n";
+        assert_eq!(frame(&sample(), &options), expected);
+    }
+
+    #[test]
+    fn animation_groups_frames_in_the_largest_extent() {
+        let frames = [
+            sample(),
+            RenderedFrame::new(1, 1, Cell::new('z', Style::default())),
+        ];
+        let svg = animation(&frames, Duration::from_millis(250), &ExportOptions::new());
+        assert!(svg.contains("width=\"16.8\" height=\"33.6\""));
+        assert!(svg.contains("50.0000% { visibility: hidden; }"));
+        assert!(svg.contains(".f1 { animation-delay: 0.250s; }\n"));
+        assert!(svg.contains("<g class=\"f f1\">\n<text x=\"0.0\" y=\"8.4\" textLength=\"8.4\" lengthAdjust=\"spacingAndGlyphs\" fill=\"#d0d0d0\">z</text>\n</g>\n"));
+    }
+}
@@ -0,0 +1,396 @@
+//! Follow a file as it grows, like `tail -F`, or lines arriving on a stream.
+
+use crate::frame::text::Text;
+use anyhow::{Context, Result};
+use std::collections::VecDeque;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+
+/// Something that happened to the followed file since the last poll.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FollowEvent {
+    /// The file shrank; it's being read again from the start.
+    Truncated,
+    /// The path now points at a different file, e.g. after log rotation; the rest of the
+    /// old file was read first and the new one is read from the start.
+    Rotated,
+}
+
+/// Lines read by one `Follower::poll`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FollowUpdate {
+    pub event: Option<FollowEvent>,
+    /// Complete lines, without their line endings.
+    pub lines: Vec<String>,
+}
+
+/// Reads lines appended to a file, surviving truncation and rotation.
+///
+/// The file may disappear for a while (between a rotation's rename and create); polls
+/// return nothing until it's back.
+#[derive(Debug)]
+pub struct Follower {
+    path: PathBuf,
+    file: Option<File>,
+    id: Option<FileId>,
+    offset: u64,
+    partial: Vec<u8>,
+}
+
+impl Follower {
+    /// Follow `path`, which must exist. The first poll returns its current contents.
+    pub fn open(path: &Path) -> Result<Self> {
+        let mut follower = Self {
+            path: path.to_owned(),
+            file: None,
+            id: None,
+            offset: 0,
+            partial: vec![],
+        };
+        follower
+            .reopen()
+            .with_context(|| format!("cannot open {}", path.display()))?;
+        Ok(follower)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The unterminated last line read so far.
+    pub fn partial(&self) -> String {
+        String::from_utf8_lossy(&self.partial).into_owned()
+    }
+
+    /// Read whatever has been written since the last poll.
+    pub fn poll(&mut self) -> Result<FollowUpdate> {
+        let mut update = FollowUpdate::default();
+        let meta = match fs::metadata(&self.path) {
+            Ok(meta) => meta,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(update),
+            Err(err) => {
+                return Err(err).with_context(|| format!("cannot read {}", self.path.display()))
+            }
+        };
+        if file_id(&meta) != self.id {
+            // finish the old file before switching to the new one
+            self.read_lines(&mut update.lines)?;
+            self.flush_partial(&mut update.lines);
+            match self.reopen() {
+                Ok(()) => {}
+                // gone again since the metadata call; pick up the next one on a later poll
+                Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(update),
+                Err(err) => {
+                    return Err(err).with_context(|| format!("cannot open {}", self.path.display()))
+                }
+            }
+            update.event = Some(FollowEvent::Rotated);
+        } else if meta.len() < self.offset {
+            self.offset = 0;
+            self.partial.clear();
+            update.event = Some(FollowEvent::Truncated);
+        }
+        self.read_lines(&mut update.lines)?;
+        Ok(update)
+    }
+
+    fn reopen(&mut self) -> io::Result<()> {
+        let file = File::open(&self.path)?;
+        self.id = file_id(&file.metadata()?);
+        self.file = Some(file);
+        self.offset = 0;
+        Ok(())
+    }
+
+    /// Read from the current offset to the end, appending complete lines to `lines`.
+    fn read_lines(&mut self, lines: &mut Vec<String>) -> Result<()> {
+        let Some(file) = self.file.as_mut() else {
+            return Ok(());
+        };
+        let mut buf = vec![];
+        file.seek(SeekFrom::Start(self.offset))?;
+        self.offset += file
+            .read_to_end(&mut buf)
+            .with_context(|| format!("cannot read {}", self.path.display()))?
+            as u64;
+        self.partial.extend(buf);
+        let mut start = 0;
+        for (end, _) in self
+            .partial
+            .iter()
+            .enumerate()
+            .filter(|(_, b)| **b == b'\n')
+        {
+            lines.push(decode_line(&self.partial[start..=end]));
+            start = end + 1;
+        }
+        self.partial.drain(..start);
+        Ok(())
+    }
+
+    /// Emit the unterminated last line, e.g. before moving on from a rotated file.
+    fn flush_partial(&mut self, lines: &mut Vec<String>) {
+        if !self.partial.is_empty() {
+            lines.push(decode_line(&self.partial));
+            self.partial.clear();
+        }
+    }
+}
+
+fn decode_line(line: &[u8]) -> String {
+    let line = line.strip_suffix(b"\n").unwrap_or(line);
+    let line = line.strip_suffix(b"\r").unwrap_or(line);
+    String::from_utf8_lossy(line).into_owned()
+}
+
+/// Identity of a file, to notice when its path is replaced.
+#[cfg(unix)]
+type FileId = (u64, u64);
+#[cfg(not(unix))]
+type FileId = ();
+
+#[cfg(unix)]
+fn file_id(meta: &fs::Metadata) -> Option<FileId> {
+    use std::os::unix::fs::MetadataExt;
+    Some((meta.dev(), meta.ino()))
+}
+
+/// Without inode numbers only truncation can be detected.
+#[cfg(not(unix))]
+fn file_id(_meta: &fs::Metadata) -> Option<FileId> {
+    Some(())
+}
+
+/// Reads lines from a stream, such as stdin, on a background thread so they can be
+/// picked up as they arrive.
+pub struct LineStream {
+    receiver: Receiver<io::Result<String>>,
+    closed: bool,
+}
+
+impl LineStream {
+    pub fn spawn<R: Read + Send + 'static>(reader: R) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        std::thread::spawn(move || {
+            let mut reader = BufReader::new(reader);
+            let mut line = vec![];
+            loop {
+                line.clear();
+                match reader.read_until(b'\n', &mut line) {
+                    Ok(0) => break,
+                    Ok(_) => {
+                        if sender.send(Ok(decode_line(&line))).is_err() {
+                            break;
+                        }
+                    }
+                    Err(err) => {
+                        let _ = sender.send(Err(err));
+                        break;
+                    }
+                }
+            }
+        });
+        Self {
+            receiver,
+            closed: false,
+        }
+    }
+
+    /// Lines received since the last poll.
+    pub fn poll(&mut self) -> Result<Vec<String>> {
+        let mut lines = vec![];
+        loop {
+            match self.receiver.try_recv() {
+                Ok(line) => lines.push(line.context("cannot read stream")?),
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    self.closed = true;
+                    break;
+                }
+            }
+        }
+        Ok(lines)
+    }
+
+    /// True once the stream has ended and every line has been polled.
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+}
+
+/// The most recent lines of a stream, keeping at most `capacity` of them.
+#[derive(Clone, Debug, Default)]
+pub struct LineBuffer {
+    lines: VecDeque<String>,
+    capacity: Option<usize>,
+    dropped: u64,
+}
+
+impl LineBuffer {
+    /// A buffer that keeps every line.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A ring buffer that drops the oldest lines beyond `capacity`.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            capacity: Some(capacity),
+            ..Self::default()
+        }
+    }
+
+    pub fn push(&mut self, line: String) {
+        self.lines.push_back(line);
+        if self.capacity.is_some_and(|cap| self.lines.len() > cap) {
+            self.lines.pop_front();
+            self.dropped += 1;
+        }
+    }
+
+    /// Remove every line; line numbers start over, so the dropped count is reset too.
+    pub fn clear(&mut self) {
+        self.lines.clear();
+        self.dropped = 0;
+    }
+
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Lines dropped to stay within capacity since the last `clear`.
+    pub fn dropped(&self) -> u64 {
+        self.dropped
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &String> {
+        self.lines.iter()
+    }
+
+    /// The retained lines as frame content, with ANSI colors applied; see `Text::from_ansi`.
+    pub fn text(&self) -> Text {
+        let mut content = String::new();
+        for line in &self.lines {
+            content.push_str(line);
+            content.push('\n');
+        }
+        Text::from_ansi(&content)
+    }
+}
+
+impl Extend<String> for LineBuffer {
+    fn extend<I: IntoIterator<Item = String>>(&mut self, lines: I) {
+        for line in lines {
+            self.push(line);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    /// A scratch directory, removed on drop.
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let dir =
+                std::env::temp_dir().join(format!("cli-frame-{}-{}", name, std::process::id()));
+            let _ = fs::remove_dir_all(&dir);
+            fs::create_dir_all(&dir).unwrap();
+            Self(dir)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn append(path: &Path, content: &str) {
+        let mut file = fs::OpenOptions::new()
+            .append(true)
+            .create(true)
+            .open(path)
+            .unwrap();
+        file.write_all(content.as_bytes()).unwrap();
+    }
+
+    #[test]
+    fn reads_existing_then_appended_lines() {
+        let dir = TempDir::new("append");
+        let path = dir.0.join("log");
+        append(&path, "one\r\ntwo\n");
+        let mut follower = Follower::open(&path).unwrap();
+        assert_eq!(follower.poll().unwrap().lines, ["one", "two"]);
+        assert!(follower.poll().unwrap().lines.is_empty());
+        append(&path, "three\n");
+        let update = follower.poll().unwrap();
+        assert_eq!(
+            (update.event, update.lines),
+            (None, vec!["three".to_owned()])
+        );
+    }
+
+    #[test]
+    fn holds_partial_lines() {
+        let dir = TempDir::new("partial");
+        let path = dir.0.join("log");
+        append(&path, "a\nhal");
+        let mut follower = Follower::open(&path).unwrap();
+        assert_eq!(follower.poll().unwrap().lines, ["a"]);
+        assert_eq!(follower.partial(), "hal");
+        append(&path, "f\nb");
+        assert_eq!(follower.poll().unwrap().lines, ["half"]);
+        assert_eq!(follower.partial(), "b");
+    }
+
+    #[test]
+    fn rereads_truncated_file() {
+        let dir = TempDir::new("truncate");
+        let path = dir.0.join("log");
+        append(&path, "old line\npartial");
+        let mut follower = Follower::open(&path).unwrap();
+        follower.poll().unwrap();
+        fs::write(&path, "new\n").unwrap();
+        let update = follower.poll().unwrap();
+        assert_eq!(update.event, Some(FollowEvent::Truncated));
+        assert_eq!(update.lines, ["new"]);
+        assert_eq!(follower.partial(), "");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn follows_rotation() {
+        let dir = TempDir::new("rotate");
+        let path = dir.0.join("log");
+        append(&path, "first\n");
+        let mut follower = Follower::open(&path).unwrap();
+        follower.poll().unwrap();
+        append(&path, "last\nunterminated");
+        fs::rename(&path, dir.0.join("log.1")).unwrap();
+        // missing between the rename and the create
+        assert_eq!(follower.poll().unwrap(), FollowUpdate::default());
+        append(&path, "fresh\n");
+        let update = follower.poll().unwrap();
+        assert_eq!(update.event, Some(FollowEvent::Rotated));
+        assert_eq!(update.lines, ["last", "unterminated", "fresh"]);
+    }
+
+    #[test]
+    fn line_buffer_drops_oldest() {
+        let mut buffer = LineBuffer::with_capacity(2);
+        buffer.extend(["a", "b", "c"].map(String::from));
+        assert_eq!(buffer.iter().collect::<Vec<_>>(), ["b", "c"]);
+        assert_eq!(buffer.dropped(), 1);
+    }
+}
@@ -0,0 +1,208 @@
+use crate::frame::frame::{FrameEngine, FrameRender};
+use crate::frame::text::Text;
+use std::collections::VecDeque;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// Timing statistics collected by a `FrameClock`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FrameStats {
+    /// Frames actually drawn per second, over the last second.
+    pub fps: f32,
+    /// Time taken to draw the last frame.
+    pub render_time: Duration,
+    /// Longest time taken to draw a frame.
+    pub max_render_time: Duration,
+    pub frames_drawn: u64,
+    /// Frame slots skipped because drawing fell behind.
+    pub frames_dropped: u64,
+    pub bytes_written: u64,
+}
+
+impl fmt::Display for FrameStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:.1} fps | {:.1}ms | {} dropped | {}",
+            self.fps,
+            self.render_time.as_secs_f64() * 1000.0,
+            self.frames_dropped,
+            human_bytes(self.bytes_written)
+        )
+    }
+}
+
+/// Paces rendering at a target frame rate.
+///
+/// `tick` sleeps until the next frame is due and returns its index. When drawing falls
+/// behind, the late slots are skipped rather than queued, so animations indexed by the
+/// returned frame number keep real-time speed.
+#[derive(Clone, Debug)]
+pub struct FrameClock {
+    interval: Duration,
+    /// Show the stats in the frame border when drawing through the clock.
+    pub overlay: bool,
+    next: Option<Instant>,
+    frame: u64,
+    history: VecDeque<Instant>,
+    stats: FrameStats,
+}
+
+impl FrameClock {
+    pub fn new(fps: f32) -> Self {
+        Self {
+            interval: interval(fps),
+            overlay: false,
+            next: None,
+            frame: 0,
+            history: VecDeque::new(),
+            stats: FrameStats::default(),
+        }
+    }
+
+    pub fn with_overlay(mut self, overlay: bool) -> Self {
+        self.overlay = overlay;
+        self
+    }
+
+    pub fn set_fps(&mut self, fps: f32) {
+        self.interval = interval(fps);
+    }
+
+    pub fn target_fps(&self) -> f32 {
+        1.0 / self.interval.as_secs_f32()
+    }
+
+    pub fn stats(&self) -> FrameStats {
+        self.stats
+    }
+
+    /// Time left until the next frame is due.
+    pub fn until_next(&self) -> Duration {
+        self.next
+            .map(|next| next.saturating_duration_since(Instant::now()))
+            .unwrap_or(Duration::ZERO)
+    }
+
+    /// Wait for the next frame slot and return its index, counting any skipped slots as dropped.
+    pub fn tick(&mut self) -> u64 {
+        let now = Instant::now();
+        match self.next {
+            None => self.next = Some(now + self.interval),
+            Some(next) if now < next => {
+                std::thread::sleep(next - now);
+                self.frame += 1;
+                self.next = Some(next + self.interval);
+            }
+            Some(next) => {
+                let behind = ((now - next).as_nanos() / self.interval.as_nanos()) as u64;
+                self.stats.frames_dropped += behind;
+                self.frame += 1 + behind;
+                let slots = u32::try_from(behind + 1).unwrap_or(u32::MAX);
+                self.next = Some(next + self.interval.saturating_mul(slots));
+            }
+        }
+
+        let now = Instant::now();
+        self.history.push_back(now);
+        while self
+            .history
+            .front()
+            .is_some_and(|t| now - *t > Duration::from_secs(1))
+        {
+            self.history.pop_front();
+        }
+        if let (Some(first), true) = (self.history.front(), self.history.len() > 1) {
+            let span = (now - *first).as_secs_f32();
+            self.stats.fps = (self.history.len() - 1) as f32 / span;
+        }
+        self.frame
+    }
+
+    /// Forget the slots that passed while there was nothing to draw, so they aren't
+    /// counted as dropped by the next `tick`.
+    pub fn resync(&mut self) {
+        let now = Instant::now();
+        if self.next.is_some_and(|next| next < now) {
+            self.next = Some(now);
+        }
+    }
+
+    /// Record a frame drawn some other way.
+    pub fn record(&mut self, render_time: Duration, bytes: u64) {
+        self.stats.frames_drawn += 1;
+        self.stats.render_time = render_time;
+        self.stats.max_render_time = self.stats.max_render_time.max(render_time);
+        self.stats.bytes_written += bytes;
+    }
+
+    /// Draw `content` into `engine`, timing it and updating the overlay.
+    pub fn draw<R: FrameRender>(&mut self, engine: &mut FrameEngine<R>, content: &Text) {
+        let overlay = self.overlay.then(|| format!(" {} ", self.stats));
+        engine.set_overlay(overlay.as_deref());
+        let (start, bytes) = (Instant::now(), engine.bytes_written());
+        engine.update_text(content);
+        self.record(start.elapsed(), engine.bytes_written() - bytes);
+    }
+}
+
+/// Never zero, so a huge or infinite `fps` can't make `tick` divide by zero.
+fn interval(fps: f32) -> Duration {
+    Duration::from_secs_f32(1.0 / fps.max(0.001)).max(Duration::from_nanos(1))
+}
+
+/// `12345` -> `12.1KiB`.
+fn human_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut n = bytes as f64;
+    let mut i = 0;
+    while n >= 1024.0 && i < UNITS.len() - 1 {
+        n /= 1024.0;
+        i += 1;
+    }
+    if i == 0 {
+        format!("{}B", bytes)
+    } else {
+        format!("{:.1}{}", n, UNITS[i])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interval_is_never_zero() {
+        for fps in [f32::INFINITY, f32::MAX, f32::NAN, 0.0, -5.0] {
+            assert!(interval(fps) >= Duration::from_nanos(1), "fps {}", fps);
+        }
+        let mut clock = FrameClock::new(f32::INFINITY);
+        clock.tick();
+        clock.tick();
+        clock.set_fps(f32::INFINITY);
+        clock.tick();
+    }
+
+    #[test]
+    fn on_time_ticks_drop_nothing() {
+        let mut clock = FrameClock::new(20.0);
+        assert_eq!(clock.tick(), 0);
+        assert_eq!(clock.tick(), 1);
+        assert_eq!(clock.tick(), 2);
+        assert_eq!(clock.stats().frames_dropped, 0);
+    }
+
+    #[test]
+    fn late_tick_skips_and_counts_missed_slots() {
+        let mut clock = FrameClock::new(100.0);
+        clock.tick();
+        std::thread::sleep(Duration::from_millis(55));
+        let frame = clock.tick();
+        assert!(frame >= 4, "frame {}", frame);
+        assert_eq!(clock.stats().frames_dropped, frame - 1);
+        // The next slot is back on the grid, so an immediate tick drops nothing more.
+        let dropped = clock.stats().frames_dropped;
+        assert_eq!(clock.tick(), frame + 1);
+        assert_eq!(clock.stats().frames_dropped, dropped);
+    }
+}
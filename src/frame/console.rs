@@ -1,6 +1,11 @@
 use crate::frame::FrameRender;
+use anyhow::{Context, Result};
 use crossterm::cursor::{DisableBlinking, MoveTo, SavePosition};
 use crossterm::execute;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
 
 use super::{FrameConfig, FrameEngine};
 
@@ -8,6 +13,73 @@ use super::{FrameConfig, FrameEngine};
 pub struct ConsoleFrame {
     width: usize,
     height: usize,
+    tty: Option<Arc<File>>,
+    written: Arc<AtomicU64>,
+}
+
+/// Where a `ConsoleFrame` draws: stdout, or the terminal opened by `ConsoleFrame::tty`.
+#[derive(Clone)]
+pub struct ConsoleWriter {
+    tty: Option<Arc<File>>,
+    written: Arc<AtomicU64>,
+}
+
+impl Write for ConsoleWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = match &self.tty {
+            Some(tty) => (&**tty).write(buf),
+            None => io::stdout().write(buf),
+        }?;
+        self.written.fetch_add(n as u64, Ordering::Relaxed);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match &self.tty {
+            Some(tty) => (&**tty).flush(),
+            None => io::stdout().flush(),
+        }
+    }
+}
+
+impl ConsoleFrame {
+    /// A console frame that draws to the controlling terminal instead of stdout, so it
+    /// still works when stdin and stdout are piped.
+    pub fn tty() -> Result<Self> {
+        let tty = OpenOptions::new()
+            .write(true)
+            .open("/dev/tty")
+            .context("cannot open /dev/tty")?;
+        let (width, height) = crossterm::terminal::size()
+            .map(|(w, h)| (w as usize, h as usize))
+            .unwrap_or((80, 24));
+        Ok(Self {
+            width,
+            height: height.saturating_sub(1),
+            tty: Some(Arc::new(tty)),
+            written: Arc::default(),
+        })
+    }
+
+    /// Writer for escape sequences that should go wherever the frame is drawn.
+    pub fn writer(&self) -> ConsoleWriter {
+        ConsoleWriter {
+            tty: self.tty.clone(),
+            written: self.written.clone(),
+        }
+    }
+
+    /// Current terminal size, leaving the last row free.
+    pub fn size(&self) -> (usize, usize) {
+        let size = match self.tty {
+            Some(_) => crossterm::terminal::size()
+                .ok()
+                .map(|(w, h)| (w as usize, h as usize)),
+            None => term_size::dimensions(),
+        };
+        size.map(|(w, h)| (w, h.saturating_sub(1)))
+            .unwrap_or((self.width, self.height))
+    }
 }
 
 impl FrameRender for ConsoleFrame {
@@ -19,10 +91,18 @@ impl FrameRender for ConsoleFrame {
         } else {
             (80, 24)
         };
-        Self { width, height }
+        // leave the last row free so the trailing newline doesn't scroll the frame
+        let height = height.saturating_sub(1);
+        Self {
+            width,
+            height,
+            tty: None,
+            written: Arc::default(),
+        }
     }
 
     /// Create a new frame with the given configuration.
+    #[allow(refining_impl_trait)]
     fn new_frame_engine(&self, config: &FrameConfig) -> FrameEngine<Self> {
         let config = config
             .clone()
@@ -33,26 +113,29 @@ impl FrameRender for ConsoleFrame {
 
     /// Render a **single, pre-formatted** line of text to the console.
     fn render_line(&self, line: &str) {
-        println!("{}", line)
+        // explicit carriage return so lines still start at column 0 in raw mode
+        write!(self.writer(), "{}\r\n", line).expect("Failed to render line");
     }
 
     /// Clear the console.
     fn clear(&self, clear_char: char) {
         self.reset_cursor();
-        for _ in 0..self.height {
-            self.render_line(&format!("{}", clear_char.to_string().repeat(self.width)));
+        // the terminal may have been resized since the frame was created
+        let (width, height) = self.size();
+        for _ in 0..height {
+            self.render_line(&clear_char.to_string().repeat(width));
             // SPACE * (self.width)
         }
     }
 
+    /// Bytes written through this frame and its writers, escape sequences included.
+    fn bytes_written(&self) -> Option<u64> {
+        Some(self.written.load(Ordering::Relaxed))
+    }
+
     /// Reset the cursor to the top left of the terminal.
     fn reset_cursor(&self) {
-        execute!(
-            std::io::stdout(),
-            SavePosition,
-            DisableBlinking,
-            MoveTo(0, 0),
-        )
-        .expect("Failed to reset cursor");
+        execute!(self.writer(), SavePosition, DisableBlinking, MoveTo(0, 0))
+            .expect("Failed to reset cursor");
     }
 }
@@ -1,2 +1,6 @@
 pub const FRAME_BG: char = '▓';
 pub const SPACE: char = ' ';
+/// Shown in the line-number gutter on rows continuing a wrapped line.
+pub const WRAP_MARKER: char = '↪';
+/// Separates the line-number gutter from the content.
+pub const GUTTER_SEPARATOR: char = '│';
@@ -1,4 +1,11 @@
-use crate::frame::constants::{FRAME_BG, SPACE};
+use crate::frame::constants::{FRAME_BG, GUTTER_SEPARATOR, SPACE, WRAP_MARKER};
+use crate::frame::rendered::{Cell, RenderedFrame};
+use crate::frame::style::Style;
+use crate::frame::text::{Line, Span, Text};
+use crate::frame::transition::Transition;
+use crate::terminal::interpret;
+use std::ops::Range;
+use std::time::Duration;
 
 /// Implement the FrameRender trait to render frames for a custom writer.
 pub trait FrameRender {
@@ -12,15 +19,27 @@ pub trait FrameRender {
     fn reset_cursor(&self);
     /// Clear the interface.
     fn clear(&self, clear_char: char);
+    /// Total bytes written to the output so far, if the renderer counts them.
+    fn bytes_written(&self) -> Option<u64> {
+        None
+    }
 }
 
 pub struct FrameEngine<R: FrameRender> {
-    frame_buffer: Box<[String]>,
+    frame_buffer: Box<[Line]>,
     config: FrameConfig,
     render_engine: R,
+    overlay: Option<String>,
+    /// Index of the first laid-out line shown in the content area.
+    scroll: usize,
+    bytes_written: u64,
+    /// Size of the last frame drawn; a frame of the same size is drawn over it in place.
+    drawn: Option<(usize, usize)>,
+    /// Lines of the content before wrapping, for sizing the line-number gutter.
+    source_lines: usize,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct FrameConfig {
     pub border_thickness: usize,
     pub padding: usize,
@@ -29,6 +48,26 @@ pub struct FrameConfig {
     pub height: usize,
     pub space_char: char,
     pub frame_char: char,
+    pub frame_style: Style,
+    pub content_style: Style,
+    /// Effect played by `update` when replacing existing content.
+    pub transition: Transition,
+    pub transition_duration: Duration,
+    /// Keep the view scrolled to the bottom as content grows, unless scrolled up.
+    pub stick_to_bottom: bool,
+    /// Label drawn in the top border.
+    pub title: Option<String>,
+    /// Label drawn in the bottom border.
+    pub footer: Option<String>,
+    /// Interpret carriage returns, cursor movement and SGR colors in content given to
+    /// `update` and `compose`, the way a terminal would; see `terminal::Transcript`.
+    pub interpret_escapes: bool,
+    /// Show each line's number in a gutter left of the content, and mark the rows
+    /// continuing a wrapped line.
+    pub line_numbers: bool,
+    /// Number of the first line, e.g. when earlier lines were dropped.
+    pub first_line_number: usize,
+    pub gutter_style: Style,
 }
 
 impl Default for FrameConfig {
@@ -41,6 +80,17 @@ impl Default for FrameConfig {
             height: 24,
             space_char: SPACE,
             frame_char: FRAME_BG,
+            frame_style: Style::default(),
+            content_style: Style::default(),
+            transition: Transition::Cut,
+            transition_duration: Duration::from_millis(400),
+            stick_to_bottom: false,
+            title: None,
+            footer: None,
+            interpret_escapes: false,
+            line_numbers: false,
+            first_line_number: 1,
+            gutter_style: Style::new().with_dim(true),
         }
     }
 }
@@ -60,6 +110,61 @@ impl FrameConfig {
         self
     }
 
+    pub fn with_frame_style(mut self, frame_style: Style) -> Self {
+        self.frame_style = frame_style;
+        self
+    }
+
+    pub fn with_content_style(mut self, content_style: Style) -> Self {
+        self.content_style = content_style;
+        self
+    }
+
+    pub fn with_transition(mut self, transition: Transition) -> Self {
+        self.transition = transition;
+        self
+    }
+
+    pub fn with_transition_duration(mut self, transition_duration: Duration) -> Self {
+        self.transition_duration = transition_duration;
+        self
+    }
+
+    pub fn with_stick_to_bottom(mut self, stick_to_bottom: bool) -> Self {
+        self.stick_to_bottom = stick_to_bottom;
+        self
+    }
+
+    pub fn with_title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_owned());
+        self
+    }
+
+    pub fn with_footer(mut self, footer: &str) -> Self {
+        self.footer = Some(footer.to_owned());
+        self
+    }
+
+    pub fn with_interpret_escapes(mut self, interpret_escapes: bool) -> Self {
+        self.interpret_escapes = interpret_escapes;
+        self
+    }
+
+    pub fn with_line_numbers(mut self, line_numbers: bool) -> Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    pub fn with_first_line_number(mut self, first_line_number: usize) -> Self {
+        self.first_line_number = first_line_number;
+        self
+    }
+
+    pub fn with_gutter_style(mut self, gutter_style: Style) -> Self {
+        self.gutter_style = gutter_style;
+        self
+    }
+
     pub fn with_border_thickness(mut self, border_thickness: usize) -> Self {
         self.border_thickness = border_thickness;
         self
@@ -84,6 +189,11 @@ impl FrameConfig {
         self.height = height;
         self
     }
+
+    /// Total space taken up by the frame w/ margins + padding, along each axis.
+    pub fn framespace(&self) -> usize {
+        (self.border_thickness + self.margin + self.padding) * 2
+    }
 }
 
 impl<R: FrameRender> FrameEngine<R> {
@@ -91,136 +201,324 @@ impl<R: FrameRender> FrameEngine<R> {
         Self {
             frame_buffer: Box::new([]),
             config: config.clone(),
-            render_engine: render_engine,
+            render_engine,
+            overlay: None,
+            scroll: 0,
+            bytes_written: 0,
+            drawn: None,
+            source_lines: 0,
         }
     }
 
-    fn content_width(&self) -> usize {
-        self.config.width - self.framespace()
+    pub fn config(&self) -> &FrameConfig {
+        &self.config
     }
 
-    fn content_height(&self) -> usize {
-        self.config.height - self.framespace()
+    /// Replace the configuration; takes effect on the next update.
+    pub fn set_config(&mut self, config: &FrameConfig) {
+        self.config = config.clone();
     }
 
-    /// Total space taken up by the frame w/ margins + padding.
-    fn framespace(&self) -> usize {
-        (self.config.border_thickness + self.config.margin + self.config.padding) * 2
+    /// Change the frame size; takes effect on the next update.
+    pub fn resize(&mut self, width: usize, height: usize) {
+        self.config.width = width;
+        self.config.height = height;
     }
 
-    fn left_padding(&self) -> String {
-        " ".repeat(self.config.padding)
+    /// Show `overlay` right-aligned in the bottom border, or remove it with `None`.
+    /// Takes effect on the next render.
+    pub fn set_overlay(&mut self, overlay: Option<&str>) {
+        self.overlay = overlay.map(str::to_owned);
     }
 
-    fn left_margin(&self) -> String {
-        " ".repeat(self.config.margin)
+    /// Total bytes written so far, as counted by the `FrameRender`; falls back to the
+    /// bytes of the rendered lines if it doesn't count them.
+    pub fn bytes_written(&self) -> u64 {
+        self.render_engine
+            .bytes_written()
+            .unwrap_or(self.bytes_written)
     }
 
-    fn frame_col(&self) -> String {
-        self.config
-            .frame_char
-            .to_string()
-            .repeat(self.config.border_thickness)
+    /// Width available to content, not counting the line-number gutter.
+    pub fn content_width(&self) -> usize {
+        self.area_width().saturating_sub(self.gutter_width())
     }
 
-    fn frame_row(&self) -> String {
-        self.config
-            .frame_char
-            .to_string()
-            .repeat(self.frame_width())
+    /// Width of the line-number gutter for the current content; 0 when it's off.
+    pub fn gutter_width(&self) -> usize {
+        self.gutter_width_for(self.source_lines)
     }
 
-    fn frame_width(&self) -> usize {
-        self.config.width - (self.config.margin * 2)
+    /// Gutter width for content of `lines` lines: the digits of the last line number,
+    /// the separator and a space.
+    fn gutter_width_for(&self, lines: usize) -> usize {
+        if self.config.line_numbers {
+            let last = self.config.first_line_number + lines.saturating_sub(1);
+            last.to_string().len() + 2
+        } else {
+            0
+        }
     }
 
-    fn clear(&mut self) {
-        self.render_engine.clear(self.config.space_char);
-        self.frame_buffer = Box::new([]);
+    /// Width of the content area, gutter included.
+    fn area_width(&self) -> usize {
+        self.config.width.saturating_sub(self.framespace())
     }
 
-    /// Render the frame.
-    fn render(&mut self) {
-        if self.frame_buffer.len() > 0 {
-            if self.frame_buffer.len() + self.framespace() > self.config.height {
-                // crop content if it's too large
-                self.frame_buffer = self.frame_buffer[..self.content_height()]
-                    .to_owned()
-                    .into_boxed_slice();
+    pub fn content_height(&self) -> usize {
+        self.config.height.saturating_sub(self.framespace())
+    }
+
+    /// Total space taken up by the frame w/ margins + padding.
+    pub fn framespace(&self) -> usize {
+        self.config.framespace()
+    }
+
+    /// Wrap `content` to the content width, adding the line-number gutter if enabled.
+    pub fn layout(&self, content: &Text) -> Vec<Line> {
+        let gutter = self.gutter_width_for(content.lines.len());
+        let width = self.area_width().saturating_sub(gutter);
+        let mut buf = vec![];
+        for (i, line) in content.lines.iter().enumerate() {
+            for (row, wrapped) in wrap_line(line, width).into_iter().enumerate() {
+                if gutter == 0 {
+                    buf.push(wrapped);
+                    continue;
+                }
+                let label = match row {
+                    0 => (self.config.first_line_number + i).to_string(),
+                    _ => WRAP_MARKER.to_string(),
+                };
+                let prefix = format!("{:>2$}{} ", label, GUTTER_SEPARATOR, gutter - 2);
+                let mut spans = vec![Span::styled(&prefix, self.config.gutter_style)];
+                spans.extend(wrapped.spans);
+                buf.push(Line::new(spans));
             }
-            self.render_engine.reset_cursor();
+        }
+        buf
+    }
+
+    /// Columns and rows inside the border, including padding.
+    fn inner_area(&self) -> (Range<usize>, Range<usize>) {
+        let inset = self.config.margin + self.config.border_thickness;
+        (
+            inset..self.config.width.saturating_sub(inset),
+            inset..self.config.height.saturating_sub(inset),
+        )
+    }
+
+    /// Draw the margin, border and padding, then `lines` from `layout` into the content
+    /// area. Lines past the content height are cropped.
+    pub fn compose_lines(&self, lines: &[Line]) -> RenderedFrame {
+        let FrameConfig {
+            width,
+            height,
+            margin,
+            border_thickness,
+            padding,
+            ..
+        } = self.config;
+        let space = Cell::new(self.config.space_char, Style::default());
+        let border = Cell::new(self.config.frame_char, self.config.frame_style);
+        let inner = Cell::new(self.config.space_char, self.config.content_style);
+        let mut frame = RenderedFrame::new(width, height, space);
 
-            let top_margin = self.config.space_char.to_string().repeat(self.config.width);
-            // render top margin
-            for _ in 0..self.config.margin {
-                self.render_engine.render_line(&top_margin);
+        // border + padding + content area, inset by the margin
+        let frame_x = margin..width.saturating_sub(margin);
+        let frame_y = margin..height.saturating_sub(margin);
+        let (inner_x, inner_y) = self.inner_area();
+        for y in frame_y {
+            for x in frame_x.clone() {
+                let cell = if inner_x.contains(&x) && inner_y.contains(&y) {
+                    inner
+                } else {
+                    border
+                };
+                frame.set(x, y, cell);
             }
+        }
 
-            // render top border
-            let row_frame = format!("{}{}", self.left_margin(), self.frame_row());
-            let row_frame = (0..self.config.border_thickness)
-                .map(|_| row_frame.clone())
-                .collect::<Vec<String>>()
-                .join("\n");
-            self.render_engine.render_line(&row_frame);
-
-            // render top padding
-            let inner_padding_row = format!(
-                "{}{}{}{}",
-                self.left_margin(),
-                self.frame_col(),
-                self.config
-                    .space_char
-                    .to_string()
-                    .repeat(self.content_width() + (self.config.padding * 2)),
-                self.frame_col(),
-            );
-            self.render_engine.render_line(&inner_padding_row);
-
-            // render lines
-            for line in self.frame_buffer.iter() {
-                let right_padding = self
-                    .config
-                    .space_char
-                    .to_string()
-                    .repeat(self.content_width() - line.len() + self.config.padding);
-                self.render_engine.render_line(&format!(
-                    "{}{}{}{}{}{}",
-                    self.left_margin(),
-                    self.frame_col(),
-                    self.left_padding(),
-                    line,
-                    right_padding,
-                    self.frame_col(),
-                ));
+        let content_x = margin + border_thickness + padding;
+        let content_y = margin + border_thickness + padding;
+        for (row, line) in lines.iter().take(self.content_height()).enumerate() {
+            for (col, (ch, style)) in line.cells().take(self.area_width()).enumerate() {
+                frame.set(
+                    content_x + col,
+                    content_y + row,
+                    Cell::new(ch, self.config.content_style.patch(style)),
+                );
             }
+        }
 
-            // render bottom padding & border
-            for _ in 0..(self.config.height - self.frame_buffer.len() - self.framespace()) {
-                self.render_engine.render_line(&inner_padding_row);
+        if border_thickness > 0 {
+            let (top, bottom) = (margin, height.saturating_sub(margin + 1));
+            if let Some(title) = &self.config.title {
+                self.border_label(&mut frame, top, title, false);
+            }
+            if let Some(footer) = &self.config.footer {
+                self.border_label(&mut frame, bottom, footer, false);
+            }
+            if let Some(overlay) = &self.overlay {
+                self.border_label(&mut frame, bottom, overlay, true);
             }
-            self.render_engine.render_line(&row_frame);
+        }
+        frame
+    }
+
+    /// Write `label` into border row `y`, inset from the corners and cut to fit.
+    fn border_label(&self, frame: &mut RenderedFrame, y: usize, label: &str, right: bool) {
+        let start = self.config.margin + 2;
+        let end = self.config.width.saturating_sub(self.config.margin + 2);
+        let len = label.chars().count().min(end.saturating_sub(start));
+        let x = if right { end - len } else { start };
+        for (i, ch) in label.chars().take(len).enumerate() {
+            frame.set(x + i, y, Cell::new(ch, self.config.frame_style));
+        }
+    }
+
+    /// Content given as a string: SGR colors become styles, and the rest of the escape
+    /// sequences are interpreted if `interpret_escapes` is set.
+    pub(crate) fn parse(&self, content: &str) -> Text {
+        if self.config.interpret_escapes {
+            interpret(content)
+        } else {
+            Text::from_ansi(content)
+        }
+    }
+
+    /// Lay out `content` inside the frame without rendering it.
+    pub fn compose(&self, content: &str) -> RenderedFrame {
+        self.compose_text(&self.parse(content))
+    }
+
+    /// Lay out styled `content` inside the frame without rendering it.
+    pub fn compose_text(&self, content: &Text) -> RenderedFrame {
+        self.compose_lines(&self.layout(content))
+    }
+
+    /// The frame as of the last `update`.
+    pub fn snapshot(&self) -> RenderedFrame {
+        self.compose_lines(&self.frame_buffer[self.scroll.min(self.frame_buffer.len())..])
+    }
+
+    /// Render the frame.
+    fn render(&mut self) {
+        if !self.frame_buffer.is_empty() {
+            self.render_frame(&self.snapshot());
+        }
+    }
+
+    fn render_frame(&mut self, frame: &RenderedFrame) {
+        self.drawn = Some((frame.width(), frame.height()));
+        self.render_engine.reset_cursor();
+        for y in 0..frame.height() {
+            let line = frame.ansi_line(y);
+            self.bytes_written += line.len() as u64;
+            self.render_engine.render_line(&line);
+        }
+    }
+
+    /// Play `transition` from `from` to the current buffer over `duration`.
+    fn play_transition(
+        &mut self,
+        from: &RenderedFrame,
+        transition: Transition,
+        duration: Duration,
+    ) {
+        let to = self.snapshot();
+        let blank = Cell::new(self.config.space_char, self.config.content_style);
+        let steps = (duration.as_secs_f32() * TRANSITION_FPS).round() as u32;
+        for step in 1..steps {
+            let progress = step as f32 / steps as f32;
+            let frame = transition.apply(from, &to, self.inner_area(), blank, progress);
+            self.render_frame(&frame);
+            std::thread::sleep(duration / steps);
         }
     }
 
+    /// Number of lines in the frame buffer after wrapping.
+    pub fn line_count(&self) -> usize {
+        self.frame_buffer.len()
+    }
+
+    /// Index of the first buffered line shown in the content area.
+    pub fn scroll_offset(&self) -> usize {
+        self.scroll
+    }
+
+    /// Largest scroll offset that still fills the content area.
+    pub fn max_scroll(&self) -> usize {
+        self.frame_buffer
+            .len()
+            .saturating_sub(self.content_height())
+    }
+
+    /// Scroll so buffered line `offset` is at the top, clamped to the content.
+    /// Returns whether the offset changed; call `redraw` to show it.
+    pub fn scroll_to(&mut self, offset: usize) -> bool {
+        let offset = offset.min(self.max_scroll());
+        let changed = offset != self.scroll;
+        self.scroll = offset;
+        changed
+    }
+
+    /// Scroll by `lines`, negative toward the top.
+    pub fn scroll_by(&mut self, lines: isize) -> bool {
+        self.scroll_to(self.scroll.saturating_add_signed(lines))
+    }
+
+    /// Render the current buffer again, e.g. after scrolling.
+    pub fn redraw(&mut self) {
+        self.render();
+    }
+
     /// Update the frame; calls `clear`, updates buffer, then calls `render`.
     pub fn update(&mut self, content: &str) {
-        self.clear();
-        let mut buf = vec![];
-        for line in content.lines() {
-            buf.extend(wrap_line(line, self.content_width()));
+        self.update_text(&self.parse(content));
+    }
+
+    /// Update the frame with styled content; span styles are layered over `content_style`.
+    pub fn update_text(&mut self, content: &Text) {
+        let (transition, duration) = (self.config.transition, self.config.transition_duration);
+        self.update_text_with(content, transition, duration);
+    }
+
+    /// Update the frame, playing `transition` from the old content over `duration`.
+    /// Blocks until the transition finishes.
+    pub fn update_text_with(&mut self, content: &Text, transition: Transition, duration: Duration) {
+        let previous = (!self.frame_buffer.is_empty() && transition != Transition::Cut)
+            .then(|| self.snapshot());
+        let at_bottom = self.scroll >= self.max_scroll();
+        self.source_lines = content.lines.len();
+        self.frame_buffer = self.layout(content).into_boxed_slice();
+        self.scroll = if self.config.stick_to_bottom && at_bottom {
+            self.max_scroll()
+        } else {
+            self.scroll.min(self.max_scroll())
+        };
+        // a transition or a frame the same size as the last one overwrites every cell,
+        // so clearing first would only make the redraw flicker
+        if let Some(from) = previous {
+            self.play_transition(&from, transition, duration);
+        } else if self.frame_buffer.is_empty()
+            || self.drawn != Some((self.config.width, self.config.height))
+        {
+            self.render_engine.clear(self.config.space_char);
+            self.drawn = None;
         }
-        self.frame_buffer = buf.into_boxed_slice();
         self.render();
     }
 }
 
+/// Frames per second used to play transitions.
+const TRANSITION_FPS: f32 = 30.0;
+
 /// Splits line into multiple lines by taking `width` characters per line.
-fn wrap_line(line: &str, width: usize) -> Vec<String> {
-    let mut lines: Vec<String> = vec![];
-    if line.len() > width {
+fn wrap_line(line: &Line, width: usize) -> Vec<Line> {
+    let mut lines: Vec<Line> = vec![];
+    if line.width() > width && width > 0 {
         let (truncated, remainder) = line.split_at(width);
-        lines.push(truncated.to_owned());
+        lines.push(truncated);
         lines.extend(wrap_line(&remainder.trim_start(), width));
     } else {
         lines.push(line.to_owned());
@@ -228,3 +526,121 @@ fn wrap_line(line: &str, width: usize) -> Vec<String> {
 
     lines
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frame::style::{Color, SGR_RESET};
+
+    /// Renders nowhere.
+    #[derive(Clone)]
+    struct NoRender;
+
+    impl FrameRender for NoRender {
+        fn new() -> Self {
+            Self
+        }
+
+        #[allow(refining_impl_trait)]
+        fn new_frame_engine(&self, config: &FrameConfig) -> FrameEngine<Self> {
+            FrameEngine::new(config, Self)
+        }
+
+        fn render_line(&self, _line: &str) {}
+
+        fn reset_cursor(&self) {}
+
+        fn clear(&self, _clear_char: char) {}
+    }
+
+    fn engine(width: usize, height: usize) -> FrameEngine<NoRender> {
+        let config = FrameConfig::new()
+            .with_width(width)
+            .with_height(height)
+            .with_margin(0)
+            .with_padding(0);
+        NoRender.new_frame_engine(&config)
+    }
+
+    fn red() -> Style {
+        Style::new().with_fg(Color::DarkRed)
+    }
+
+    #[test]
+    fn wrap_line_keeps_styles() {
+        let line = Line::new(vec![Span::raw("ab "), Span::styled("cdefg", red())]);
+        let lines = wrap_line(&line, 4);
+        assert_eq!(
+            lines,
+            [
+                Line::new(vec![Span::raw("ab "), Span::styled("c", red())]),
+                Line::new(vec![Span::styled("defg", red())]),
+            ]
+        );
+    }
+
+    #[test]
+    fn wrap_line_short_and_zero_width() {
+        let line = Line::raw("abc");
+        assert_eq!(wrap_line(&line, 3), [Line::raw("abc")]);
+        assert_eq!(wrap_line(&line, 0), [line]);
+    }
+
+    #[test]
+    fn escapes_survive_wrapping() {
+        let engine = engine(6, 4);
+        let lines = engine.layout(&Text::from_ansi("\x1b[31mabcdef\x1b[0mg"));
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0], Line::new(vec![Span::styled("abcd", red())]));
+        assert_eq!(
+            lines[1],
+            Line::new(vec![Span::styled("ef", red()), Span::raw("g")])
+        );
+    }
+
+    #[test]
+    fn ansi_line_resets_before_right_border() {
+        let engine = engine(6, 3);
+        let frame = engine.compose_text(&Text::from_ansi("\x1b[31mabcd"));
+        let line = frame.ansi_line(1);
+        let border = FrameConfig::new().frame_char.to_string();
+        assert!(line.starts_with(&border));
+        assert!(
+            line.ends_with(&format!("abcd{}{}", SGR_RESET, border)),
+            "{:?}",
+            line
+        );
+    }
+
+    #[test]
+    fn line_numbers_mark_continuations() {
+        let mut engine = engine(10, 5);
+        engine.set_config(&engine.config().clone().with_line_numbers(true));
+        let lines = engine.layout(&Text::raw("abcdefgh\nx"));
+        let plain: Vec<String> = lines.iter().map(Line::plain).collect();
+        assert_eq!(
+            plain,
+            [
+                format!("1{} abcde", GUTTER_SEPARATOR),
+                format!("{}{} fgh", WRAP_MARKER, GUTTER_SEPARATOR),
+                format!("2{} x", GUTTER_SEPARATOR),
+            ]
+        );
+    }
+
+    #[test]
+    fn line_numbers_start_at_first_line_number() {
+        let mut engine = engine(12, 5);
+        let config = engine.config().clone().with_line_numbers(true);
+        engine.set_config(&config.with_first_line_number(99));
+        let lines = engine.layout(&Text::raw("a\nb"));
+        let plain: Vec<String> = lines.iter().map(Line::plain).collect();
+        assert_eq!(
+            plain,
+            [
+                format!(" 99{} a", GUTTER_SEPARATOR),
+                format!("100{} b", GUTTER_SEPARATOR),
+            ]
+        );
+    }
+}
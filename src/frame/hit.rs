@@ -0,0 +1,205 @@
+use crate::frame::frame::{FrameEngine, FrameRender};
+use crate::input::MouseInput;
+
+/// Part of a frame a point falls in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Region {
+    Margin,
+    Border,
+    Padding,
+    Content,
+}
+
+/// Where a point lands in a frame.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Hit {
+    pub region: Region,
+    /// Position relative to the frame's top-left cell.
+    pub frame: (usize, usize),
+    /// Position relative to the content, when on it rather than the line-number gutter.
+    pub content: Option<(usize, usize)>,
+    /// Index of the buffered line under the point, accounting for scrolling.
+    pub line: Option<usize>,
+}
+
+/// A rectangle of terminal cells.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Rect {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Rect {
+    pub fn new(x: usize, y: usize, width: usize, height: usize) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    pub fn contains(&self, column: usize, row: usize) -> bool {
+        (self.x..self.x + self.width).contains(&column)
+            && (self.y..self.y + self.height).contains(&row)
+    }
+}
+
+/// Routes terminal positions to whichever target is drawn there, e.g. the panes of a layout.
+#[derive(Clone, Debug)]
+pub struct HitRouter<T> {
+    targets: Vec<(Rect, T)>,
+}
+
+impl<T> Default for HitRouter<T> {
+    fn default() -> Self {
+        Self { targets: vec![] }
+    }
+}
+
+impl<T> HitRouter<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `target` as drawn in `rect`; later targets sit on top of earlier ones.
+    pub fn add(&mut self, rect: Rect, target: T) {
+        self.targets.push((rect, target));
+    }
+
+    pub fn with_target(mut self, rect: Rect, target: T) -> Self {
+        self.add(rect, target);
+        self
+    }
+
+    pub fn clear(&mut self) {
+        self.targets.clear();
+    }
+
+    /// The topmost target at (`column`, `row`) and the position relative to its rect.
+    pub fn route(&self, column: usize, row: usize) -> Option<(&T, usize, usize)> {
+        self.targets
+            .iter()
+            .rev()
+            .find(|(rect, _)| rect.contains(column, row))
+            .map(|(rect, target)| (target, column - rect.x, row - rect.y))
+    }
+}
+
+impl<R: FrameRender> FrameEngine<R> {
+    /// Classify frame-relative point (`x`, `y`) against the margin, border, padding and
+    /// content area. `None` if it's outside the frame.
+    pub fn hit_test(&self, x: usize, y: usize) -> Option<Hit> {
+        let config = self.config();
+        if x >= config.width || y >= config.height {
+            return None;
+        }
+        let within = |inset: usize| {
+            Rect::new(
+                inset,
+                inset,
+                config.width.saturating_sub(inset * 2),
+                config.height.saturating_sub(inset * 2),
+            )
+            .contains(x, y)
+        };
+        let border = config.margin;
+        let padding = border + config.border_thickness;
+        let content_inset = padding + config.padding;
+        let region = if !within(border) {
+            Region::Margin
+        } else if !within(padding) {
+            Region::Border
+        } else if !within(content_inset) {
+            Region::Padding
+        } else {
+            Region::Content
+        };
+        // clicks on the line-number gutter are on the line but not on its content
+        let gutter = self.gutter_width();
+        let content = (region == Region::Content && x - content_inset >= gutter)
+            .then(|| (x - content_inset - gutter, y - content_inset));
+        let line = (region == Region::Content)
+            .then(|| self.scroll_offset() + y - content_inset)
+            .filter(|line| *line < self.line_count());
+        Some(Hit {
+            region,
+            frame: (x, y),
+            content,
+            line,
+        })
+    }
+
+    /// Hit-test a mouse event on a frame drawn at the terminal's top-left, scrolling the
+    /// content for wheel events over the frame.
+    pub fn handle_mouse(&mut self, mouse: &MouseInput) -> Option<Hit> {
+        let hit = self.hit_test(mouse.column, mouse.row)?;
+        let delta = mouse.scroll_delta();
+        if delta != 0 && self.scroll_by(delta) {
+            self.redraw();
+        }
+        Some(hit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frame::frame::FrameConfig;
+
+    struct NoRender;
+
+    impl FrameRender for NoRender {
+        fn new() -> Self {
+            Self
+        }
+
+        #[allow(refining_impl_trait)]
+        fn new_frame_engine(&self, config: &FrameConfig) -> FrameEngine<Self> {
+            FrameEngine::new(config, Self)
+        }
+
+        fn render_line(&self, _line: &str) {}
+
+        fn reset_cursor(&self) {}
+
+        fn clear(&self, _clear_char: char) {}
+    }
+
+    fn engine(line_numbers: bool) -> FrameEngine<NoRender> {
+        let config = FrameConfig::new()
+            .with_width(20)
+            .with_height(8)
+            .with_line_numbers(line_numbers);
+        let mut engine = NoRender.new_frame_engine(&config);
+        engine.update("one\ntwo\nthree");
+        engine
+    }
+
+    #[test]
+    fn regions_from_the_outside_in() {
+        let engine = engine(false);
+        let region = |x| engine.hit_test(x, 3).map(|hit| hit.region);
+        assert_eq!(region(0), Some(Region::Margin));
+        assert_eq!(region(1), Some(Region::Border));
+        assert_eq!(region(2), Some(Region::Padding));
+        assert_eq!(region(3), Some(Region::Content));
+        assert_eq!(region(20), None);
+        let hit = engine.hit_test(4, 4).unwrap();
+        assert_eq!((hit.content, hit.line), (Some((1, 1)), Some(1)));
+    }
+
+    #[test]
+    fn gutter_is_not_content() {
+        let engine = engine(true);
+        let gutter = engine.gutter_width();
+        assert!(gutter > 0);
+        let on_gutter = engine.hit_test(3, 3).unwrap();
+        assert_eq!(on_gutter.region, Region::Content);
+        assert_eq!((on_gutter.content, on_gutter.line), (None, Some(0)));
+        let first = engine.hit_test(3 + gutter, 4).unwrap();
+        assert_eq!((first.content, first.line), (Some((0, 1)), Some(1)));
+    }
+}
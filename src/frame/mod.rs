@@ -1,5 +1,20 @@
+pub mod clock;
 pub mod console;
 pub mod constants;
+#[allow(clippy::module_inception)]
 pub mod frame;
+pub mod hit;
+pub mod render_thread;
+pub mod rendered;
+pub mod style;
+pub mod text;
+pub mod transition;
 
+pub use clock::{FrameClock, FrameStats};
 pub use frame::{FrameConfig, FrameEngine, FrameRender};
+pub use hit::{Hit, HitRouter, Rect, Region};
+pub use render_thread::{RenderSender, RenderThread};
+pub use rendered::{Cell, RenderedFrame};
+pub use style::{Color, Style};
+pub use text::{Line, Span, Text};
+pub use transition::{Direction, Transition};
@@ -0,0 +1,210 @@
+use crate::frame::clock::{FrameClock, FrameStats};
+use crate::frame::frame::{FrameConfig, FrameEngine, FrameRender};
+use crate::frame::text::Text;
+use anyhow::{anyhow, Result};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+
+enum Command {
+    Content(Text),
+    /// Content as a string, parsed by the engine the way `FrameEngine::update` would.
+    Raw(String),
+    Config(FrameConfig),
+    Resize(usize, usize),
+    /// Draw what was sent before and stop, even while other senders are alive.
+    Shutdown,
+}
+
+/// Owns a `FrameEngine` on a dedicated thread and draws whatever it's sent.
+///
+/// Updates sent faster than the frame rate are coalesced: each frame draws only the
+/// latest content, config and size. The thread stops when the handle is dropped or
+/// joined, after drawing what was sent before; senders still alive then get errors.
+pub struct RenderThread {
+    sender: Option<RenderSender>,
+    stats: Arc<Mutex<FrameStats>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+/// Cloneable sending half of a `RenderThread`, for producer threads.
+#[derive(Clone)]
+pub struct RenderSender {
+    sender: Sender<Command>,
+}
+
+impl RenderThread {
+    /// Move `engine` onto a new thread that draws at most `fps` frames per second.
+    pub fn spawn<R: FrameRender + Send + 'static>(engine: FrameEngine<R>, fps: f32) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let stats = Arc::new(Mutex::new(FrameStats::default()));
+        let thread_stats = stats.clone();
+        let thread =
+            std::thread::spawn(move || run(engine, FrameClock::new(fps), receiver, thread_stats));
+        Self {
+            sender: Some(RenderSender { sender }),
+            stats,
+            thread: Some(thread),
+        }
+    }
+
+    /// A new sender for another producer.
+    pub fn sender(&self) -> RenderSender {
+        self.sender.clone().expect("sender is only taken on drop")
+    }
+
+    /// Timing statistics from the render thread.
+    pub fn stats(&self) -> FrameStats {
+        *self.stats.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Wait for the render thread to draw everything sent so far, then stop it.
+    pub fn join(mut self) -> Result<()> {
+        self.finish()
+    }
+
+    fn finish(&mut self) -> Result<()> {
+        if let Some(sender) = self.sender.take() {
+            // fails only if the thread already stopped, which the join reports
+            let _ = sender.send(Command::Shutdown);
+        }
+        match self.thread.take() {
+            Some(thread) => thread.join().map_err(|_| anyhow!("render thread panicked")),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for RenderThread {
+    fn drop(&mut self) {
+        let _ = self.finish();
+    }
+}
+
+impl RenderSender {
+    fn send(&self, command: Command) -> Result<()> {
+        self.sender
+            .send(command)
+            .map_err(|_| anyhow!("render thread has stopped"))
+    }
+
+    /// Replace the frame content.
+    pub fn update(&self, content: &str) -> Result<()> {
+        self.send(Command::Raw(content.to_string()))
+    }
+
+    /// Replace the frame content with styled text.
+    pub fn update_text(&self, content: Text) -> Result<()> {
+        self.send(Command::Content(content))
+    }
+
+    /// Replace the frame configuration.
+    pub fn set_config(&self, config: FrameConfig) -> Result<()> {
+        self.send(Command::Config(config))
+    }
+
+    pub fn resize(&self, width: usize, height: usize) -> Result<()> {
+        self.send(Command::Resize(width, height))
+    }
+}
+
+fn run<R: FrameRender>(
+    mut engine: FrameEngine<R>,
+    mut clock: FrameClock,
+    receiver: Receiver<Command>,
+    stats: Arc<Mutex<FrameStats>>,
+) {
+    let mut content = Text::default();
+    // block while idle, then wait out the frame slot so a burst can pile up behind it
+    while let Ok(first) = receiver.recv() {
+        if let Command::Shutdown = first {
+            return;
+        }
+        clock.resync();
+        clock.tick();
+        let mut shutdown = false;
+        for command in std::iter::once(first).chain(receiver.try_iter()) {
+            match command {
+                Command::Content(text) => content = text,
+                Command::Raw(raw) => content = engine.parse(&raw),
+                Command::Config(config) => engine.set_config(&config),
+                Command::Resize(width, height) => engine.resize(width, height),
+                Command::Shutdown => {
+                    shutdown = true;
+                    break;
+                }
+            }
+        }
+        clock.draw(&mut engine, &content);
+        *stats.lock().unwrap_or_else(|e| e.into_inner()) = clock.stats();
+        if shutdown {
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frame::FrameRender;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Counts rendered lines and keeps the last frame's worth.
+    #[derive(Clone, Default)]
+    struct Counter(Arc<AtomicUsize>, Arc<Mutex<Vec<String>>>);
+
+    impl FrameRender for Counter {
+        fn new() -> Self {
+            Self::default()
+        }
+
+        #[allow(refining_impl_trait)]
+        fn new_frame_engine(&self, config: &FrameConfig) -> FrameEngine<Self> {
+            FrameEngine::new(config, self.clone())
+        }
+
+        fn render_line(&self, line: &str) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+            self.1.lock().unwrap().push(line.to_string());
+        }
+
+        fn reset_cursor(&self) {
+            self.1.lock().unwrap().clear();
+        }
+
+        fn clear(&self, _clear_char: char) {}
+    }
+
+    #[test]
+    fn drop_stops_with_live_senders() {
+        let counter = Counter::new();
+        let engine = counter.new_frame_engine(&FrameConfig::new().with_height(5));
+        let render = RenderThread::spawn(engine, 1000.0);
+        let sender = render.sender();
+        sender.update("hello").unwrap();
+        drop(render);
+        assert!(counter.0.load(Ordering::Relaxed) > 0);
+        assert!(sender.update("again").is_err());
+    }
+
+    #[test]
+    fn update_parses_like_the_engine() {
+        let config = FrameConfig::new().with_width(20).with_height(10);
+        let text = "\x1b[31mred\x1b[0m plain";
+        let (threaded, direct) = (Counter::new(), Counter::new());
+        let render = RenderThread::spawn(threaded.new_frame_engine(&config), 1000.0);
+        render.sender().update(text).unwrap();
+        render.join().unwrap();
+        direct.new_frame_engine(&config).update(text);
+        assert_eq!(*threaded.1.lock().unwrap(), *direct.1.lock().unwrap());
+    }
+
+    #[test]
+    fn join_stops_with_live_senders() {
+        let render =
+            RenderThread::spawn(Counter::new().new_frame_engine(&FrameConfig::new()), 60.0);
+        let sender = render.sender();
+        render.join().unwrap();
+        assert!(sender.update("late").is_err());
+    }
+}
@@ -0,0 +1,101 @@
+use crate::frame::style::{Style, SGR_RESET};
+
+/// A single character cell of a rendered frame.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cell {
+    pub ch: char,
+    pub style: Style,
+}
+
+impl Cell {
+    pub fn new(ch: char, style: Style) -> Self {
+        Self { ch, style }
+    }
+}
+
+/// A fully laid-out frame: `height` rows of `width` cells, including margin, border and padding.
+///
+/// This is what `FrameEngine` draws to its `FrameRender`, and what exporters consume.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RenderedFrame {
+    width: usize,
+    height: usize,
+    rows: Vec<Vec<Cell>>,
+}
+
+impl RenderedFrame {
+    /// Create a frame of the given size with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: Cell) -> Self {
+        Self {
+            width,
+            height,
+            rows: vec![vec![fill; width]; height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn rows(&self) -> &[Vec<Cell>] {
+        &self.rows
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&Cell> {
+        self.rows.get(y).and_then(|row| row.get(x))
+    }
+
+    /// Set the cell at (`x`, `y`); out-of-bounds writes are ignored.
+    pub fn set(&mut self, x: usize, y: usize, cell: Cell) {
+        if let Some(c) = self.rows.get_mut(y).and_then(|row| row.get_mut(x)) {
+            *c = cell;
+        }
+    }
+
+    /// Plain text of row `y`, without styling.
+    pub fn row_text(&self, y: usize) -> String {
+        self.rows
+            .get(y)
+            .map(|row| row.iter().map(|c| c.ch).collect())
+            .unwrap_or_default()
+    }
+
+    /// Split row `y` into runs of consecutive cells sharing the same style.
+    pub fn spans(&self, y: usize) -> Vec<(Style, String)> {
+        let mut spans: Vec<(Style, String)> = vec![];
+        for cell in self.rows.get(y).map(|r| r.as_slice()).unwrap_or_default() {
+            match spans.last_mut() {
+                Some((style, text)) if *style == cell.style => text.push(cell.ch),
+                _ => spans.push((cell.style, cell.ch.to_string())),
+            }
+        }
+        spans
+    }
+
+    /// Row `y` as a terminal line, with SGR escapes around styled runs.
+    pub fn ansi_line(&self, y: usize) -> String {
+        let mut line = String::new();
+        for (style, text) in self.spans(y) {
+            if style.is_plain() {
+                line.push_str(&text);
+            } else {
+                line.push_str(&style.sgr());
+                line.push_str(&text);
+                line.push_str(SGR_RESET);
+            }
+        }
+        line
+    }
+
+    /// Plain text of the whole frame, one line per row.
+    pub fn text(&self) -> String {
+        (0..self.height)
+            .map(|y| self.row_text(y))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
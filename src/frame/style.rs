@@ -0,0 +1,278 @@
+pub use crossterm::style::Color;
+
+/// Visual style of a single cell: foreground/background colors and text attributes.
+///
+/// `Style::default()` is "no styling"; cells with the default style are emitted without
+/// any escape sequences, so unstyled frames render exactly as plain text.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Style {
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+    pub bold: bool,
+    pub dim: bool,
+    pub italic: bool,
+    pub underline: bool,
+    pub reverse: bool,
+}
+
+impl Style {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_fg(mut self, fg: Color) -> Self {
+        self.fg = Some(fg);
+        self
+    }
+
+    pub fn with_bg(mut self, bg: Color) -> Self {
+        self.bg = Some(bg);
+        self
+    }
+
+    pub fn with_bold(mut self, bold: bool) -> Self {
+        self.bold = bold;
+        self
+    }
+
+    pub fn with_dim(mut self, dim: bool) -> Self {
+        self.dim = dim;
+        self
+    }
+
+    pub fn with_italic(mut self, italic: bool) -> Self {
+        self.italic = italic;
+        self
+    }
+
+    pub fn with_underline(mut self, underline: bool) -> Self {
+        self.underline = underline;
+        self
+    }
+
+    pub fn with_reverse(mut self, reverse: bool) -> Self {
+        self.reverse = reverse;
+        self
+    }
+
+    pub fn is_plain(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Layer `other` on top of `self`; colors set in `other` win, attributes are combined.
+    pub fn patch(mut self, other: Style) -> Self {
+        self.fg = other.fg.or(self.fg);
+        self.bg = other.bg.or(self.bg);
+        self.bold |= other.bold;
+        self.dim |= other.dim;
+        self.italic |= other.italic;
+        self.underline |= other.underline;
+        self.reverse |= other.reverse;
+        self
+    }
+
+    /// Apply the parameters of an SGR escape sequence (`CSI ... m`) to this style.
+    /// Unknown parameters are skipped; an empty list resets, like `CSI m`.
+    pub fn apply_sgr(mut self, params: &[u16]) -> Self {
+        if params.is_empty() {
+            return Self::default();
+        }
+        let mut i = 0;
+        while i < params.len() {
+            match params[i] {
+                0 => self = Self::default(),
+                1 => self.bold = true,
+                2 => self.dim = true,
+                3 => self.italic = true,
+                4 => self.underline = true,
+                7 => self.reverse = true,
+                22 => (self.bold, self.dim) = (false, false),
+                23 => self.italic = false,
+                24 => self.underline = false,
+                27 => self.reverse = false,
+                n @ 30..=37 => self.fg = Some(ansi_color(n - 30)),
+                39 => self.fg = None,
+                n @ 40..=47 => self.bg = Some(ansi_color(n - 40)),
+                49 => self.bg = None,
+                n @ 90..=97 => self.fg = Some(ansi_color(n - 90 + 8)),
+                n @ 100..=107 => self.bg = Some(ansi_color(n - 100 + 8)),
+                n @ (38 | 48) => {
+                    let (color, used) = extended_color(&params[i + 1..]);
+                    if let Some(color) = color {
+                        if n == 38 {
+                            self.fg = Some(color);
+                        } else {
+                            self.bg = Some(color);
+                        }
+                    }
+                    i += used;
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+        self
+    }
+
+    /// SGR escape sequence that switches the terminal to this style (from a reset state).
+    pub fn sgr(&self) -> String {
+        if self.is_plain() {
+            return String::new();
+        }
+        let mut params: Vec<String> = vec![];
+        if self.bold {
+            params.push("1".to_owned());
+        }
+        if self.dim {
+            params.push("2".to_owned());
+        }
+        if self.italic {
+            params.push("3".to_owned());
+        }
+        if self.underline {
+            params.push("4".to_owned());
+        }
+        if self.reverse {
+            params.push("7".to_owned());
+        }
+        if let Some(fg) = self.fg {
+            params.push(color_param(fg, false));
+        }
+        if let Some(bg) = self.bg {
+            params.push(color_param(bg, true));
+        }
+        format!("\x1b[{}m", params.join(";"))
+    }
+}
+
+/// SGR sequence that resets all styling.
+pub const SGR_RESET: &str = "\x1b[0m";
+
+/// One of the 16 standard colors by ANSI index.
+fn ansi_color(idx: u16) -> Color {
+    match idx {
+        0 => Color::Black,
+        1 => Color::DarkRed,
+        2 => Color::DarkGreen,
+        3 => Color::DarkYellow,
+        4 => Color::DarkBlue,
+        5 => Color::DarkMagenta,
+        6 => Color::DarkCyan,
+        7 => Color::Grey,
+        8 => Color::DarkGrey,
+        9 => Color::Red,
+        10 => Color::Green,
+        11 => Color::Yellow,
+        12 => Color::Blue,
+        13 => Color::Magenta,
+        14 => Color::Cyan,
+        _ => Color::White,
+    }
+}
+
+/// The color after a `38`/`48` SGR parameter (`5;n` or `2;r;g;b`), and how many
+/// parameters it used.
+fn extended_color(params: &[u16]) -> (Option<Color>, usize) {
+    let byte = |i: usize| params.get(i).map(|&v| v.min(255) as u8);
+    match params.first() {
+        Some(5) => (byte(1).map(Color::AnsiValue), 2),
+        Some(2) => match (byte(1), byte(2), byte(3)) {
+            (Some(r), Some(g), Some(b)) => (Some(Color::Rgb { r, g, b }), 4),
+            _ => (None, params.len()),
+        },
+        _ => (None, 0),
+    }
+}
+
+fn color_param(color: Color, background: bool) -> String {
+    let base = if background { 40 } else { 30 };
+    let bright = if background { 100 } else { 90 };
+    match color {
+        Color::Reset => (base + 9).to_string(),
+        Color::Black => base.to_string(),
+        Color::DarkRed => (base + 1).to_string(),
+        Color::DarkGreen => (base + 2).to_string(),
+        Color::DarkYellow => (base + 3).to_string(),
+        Color::DarkBlue => (base + 4).to_string(),
+        Color::DarkMagenta => (base + 5).to_string(),
+        Color::DarkCyan => (base + 6).to_string(),
+        Color::Grey => (base + 7).to_string(),
+        Color::DarkGrey => bright.to_string(),
+        Color::Red => (bright + 1).to_string(),
+        Color::Green => (bright + 2).to_string(),
+        Color::Yellow => (bright + 3).to_string(),
+        Color::Blue => (bright + 4).to_string(),
+        Color::Magenta => (bright + 5).to_string(),
+        Color::Cyan => (bright + 6).to_string(),
+        Color::White => (bright + 7).to_string(),
+        Color::AnsiValue(v) => format!("{};5;{}", base + 8, v),
+        Color::Rgb { r, g, b } => format!("{};2;{};{};{}", base + 8, r, g, b),
+    }
+}
+
+/// The 16 standard colors, in ANSI index order, as xterm renders them.
+const ANSI_16: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// Approximate RGB value of a terminal color, used when rendering outside a terminal.
+/// Returns `None` for `Color::Reset`, which means "whatever the default is".
+pub fn to_rgb(color: Color) -> Option<(u8, u8, u8)> {
+    let idx = match color {
+        Color::Reset => return None,
+        Color::Rgb { r, g, b } => return Some((r, g, b)),
+        Color::AnsiValue(v) => return Some(ansi_256_to_rgb(v)),
+        Color::Black => 0,
+        Color::DarkRed => 1,
+        Color::DarkGreen => 2,
+        Color::DarkYellow => 3,
+        Color::DarkBlue => 4,
+        Color::DarkMagenta => 5,
+        Color::DarkCyan => 6,
+        Color::Grey => 7,
+        Color::DarkGrey => 8,
+        Color::Red => 9,
+        Color::Green => 10,
+        Color::Yellow => 11,
+        Color::Blue => 12,
+        Color::Magenta => 13,
+        Color::Cyan => 14,
+        Color::White => 15,
+    };
+    Some(ANSI_16[idx])
+}
+
+fn ansi_256_to_rgb(v: u8) -> (u8, u8, u8) {
+    match v {
+        0..=15 => ANSI_16[v as usize],
+        16..=231 => {
+            let v = v - 16;
+            let level = |n: u8| if n == 0 { 0 } else { 55 + n * 40 };
+            (level(v / 36), level((v / 6) % 6), level(v % 6))
+        }
+        _ => {
+            let l = 8 + (v - 232) * 10;
+            (l, l, l)
+        }
+    }
+}
+
+/// Format an RGB triple as a `#rrggbb` hex string.
+pub fn hex((r, g, b): (u8, u8, u8)) -> String {
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
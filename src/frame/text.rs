@@ -0,0 +1,259 @@
+use crate::frame::style::Style;
+use crate::terminal::{Parser, Perform};
+
+/// A run of text sharing one style.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Span {
+    pub text: String,
+    pub style: Style,
+}
+
+impl Span {
+    pub fn raw(text: &str) -> Self {
+        Self::styled(text, Style::default())
+    }
+
+    pub fn styled(text: &str, style: Style) -> Self {
+        Self {
+            text: text.to_owned(),
+            style,
+        }
+    }
+
+    /// Width in cells.
+    pub fn width(&self) -> usize {
+        self.text.chars().count()
+    }
+}
+
+/// One line of styled content.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Line {
+    pub spans: Vec<Span>,
+}
+
+impl Line {
+    pub fn new(spans: Vec<Span>) -> Self {
+        Self { spans }
+    }
+
+    pub fn raw(text: &str) -> Self {
+        Self::new(vec![Span::raw(text)])
+    }
+
+    pub fn push(&mut self, span: Span) {
+        self.spans.push(span);
+    }
+
+    /// Width in cells.
+    pub fn width(&self) -> usize {
+        self.spans.iter().map(|s| s.width()).sum()
+    }
+
+    /// Text of the line without styling.
+    pub fn plain(&self) -> String {
+        self.spans.iter().map(|s| s.text.as_str()).collect()
+    }
+
+    /// Each character of the line with its style.
+    pub fn cells(&self) -> impl Iterator<Item = (char, Style)> + '_ {
+        self.spans
+            .iter()
+            .flat_map(|s| s.text.chars().map(move |c| (c, s.style)))
+    }
+
+    /// Split into the first `at` cells and the rest.
+    pub fn split_at(&self, at: usize) -> (Line, Line) {
+        let (mut head, mut tail) = (Line::default(), Line::default());
+        let mut remaining = at;
+        for span in &self.spans {
+            let width = span.width();
+            if remaining >= width {
+                head.push(span.clone());
+                remaining -= width;
+            } else if remaining > 0 {
+                let idx = span
+                    .text
+                    .char_indices()
+                    .nth(remaining)
+                    .map(|(i, _)| i)
+                    .unwrap_or(span.text.len());
+                let (a, b) = span.text.split_at(idx);
+                head.push(Span::styled(a, span.style));
+                tail.push(Span::styled(b, span.style));
+                remaining = 0;
+            } else {
+                tail.push(span.clone());
+            }
+        }
+        (head, tail)
+    }
+
+    /// Drop leading whitespace.
+    pub fn trim_start(mut self) -> Line {
+        while let Some(first) = self.spans.first_mut() {
+            let trimmed = first.text.trim_start();
+            if trimmed.is_empty() {
+                self.spans.remove(0);
+            } else {
+                first.text = trimmed.to_owned();
+                break;
+            }
+        }
+        self
+    }
+}
+
+impl From<&str> for Line {
+    fn from(text: &str) -> Self {
+        Self::raw(text)
+    }
+}
+
+/// Styled multi-line content for a frame.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Text {
+    pub lines: Vec<Line>,
+}
+
+impl Text {
+    pub fn new(lines: Vec<Line>) -> Self {
+        Self { lines }
+    }
+
+    /// Unstyled text, split on newlines.
+    pub fn raw(content: &str) -> Self {
+        Self::new(content.lines().map(Line::raw).collect())
+    }
+
+    /// Text with ANSI SGR color codes turned into styles, split on newlines.
+    ///
+    /// Escape sequences take no space. A style stays active until it's changed, across
+    /// line breaks too, like on a terminal. Other escape sequences and control characters
+    /// except tabs are dropped; see `terminal::interpret` to apply them instead.
+    pub fn from_ansi(content: &str) -> Self {
+        let mut styled = AnsiText::default();
+        Parser::new().advance(content.as_bytes(), &mut styled);
+        let mut text = styled.text;
+        // an unterminated last line, unless all that followed the last newline were
+        // escape sequences
+        if !styled.line.spans.is_empty() {
+            text.push(styled.line);
+        }
+        text
+    }
+
+    pub fn push(&mut self, line: Line) {
+        self.lines.push(line);
+    }
+
+    /// Text without styling, one line per row.
+    pub fn plain(&self) -> String {
+        self.lines
+            .iter()
+            .map(|l| l.plain())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+impl From<&str> for Text {
+    fn from(content: &str) -> Self {
+        Self::raw(content)
+    }
+}
+
+impl From<String> for Text {
+    fn from(content: String) -> Self {
+        Self::raw(&content)
+    }
+}
+
+/// Collects the lines of `Text::from_ansi`.
+#[derive(Default)]
+struct AnsiText {
+    text: Text,
+    line: Line,
+    style: Style,
+}
+
+impl Perform for AnsiText {
+    fn print(&mut self, ch: char) {
+        match self.line.spans.last_mut() {
+            Some(span) if span.style == self.style => span.text.push(ch),
+            _ => self.line.push(Span::styled(&ch.to_string(), self.style)),
+        }
+    }
+
+    fn execute(&mut self, byte: u8) {
+        match byte {
+            b'\n' => self.text.push(std::mem::take(&mut self.line)),
+            b'\t' => self.print('\t'),
+            _ => {}
+        }
+    }
+
+    fn csi(&mut self, params: &[u16], intermediates: &[u8], action: char) {
+        if action == 'm' && intermediates.is_empty() {
+            self.style = self.style.apply_sgr(params);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frame::style::Color;
+
+    fn red() -> Style {
+        Style::new().with_fg(Color::DarkRed)
+    }
+
+    #[test]
+    fn escapes_are_zero_width() {
+        let text = Text::from_ansi("\x1b[31mred\x1b[0m \x1b[1;4mbold\x1b[m");
+        assert_eq!(text.lines.len(), 1);
+        assert_eq!(text.lines[0].width(), 8);
+        assert_eq!(text.plain(), "red bold");
+    }
+
+    #[test]
+    fn style_carries_across_newlines() {
+        let text = Text::from_ansi("\x1b[31mone\ntwo\x1b[0m\nthree");
+        assert_eq!(text.lines[0], Line::new(vec![Span::styled("one", red())]));
+        assert_eq!(text.lines[1], Line::new(vec![Span::styled("two", red())]));
+        assert_eq!(text.lines[2], Line::raw("three"));
+    }
+
+    #[test]
+    fn lines_match_str_lines() {
+        for content in ["", "a", "a\n", "a\n\nb", "a\n\n", "\n"] {
+            let (ansi, raw) = (Text::from_ansi(content), Text::raw(content));
+            assert_eq!(ansi.lines.len(), raw.lines.len(), "{:?}", content);
+            assert_eq!(ansi.plain(), raw.plain());
+        }
+    }
+
+    #[test]
+    fn trailing_escape_adds_no_line() {
+        assert_eq!(Text::from_ansi("a\n\x1b[0m").lines, [Line::raw("a")]);
+        assert_eq!(Text::from_ansi("\x1b[31m"), Text::default());
+    }
+
+    #[test]
+    fn other_escapes_and_controls_are_dropped() {
+        let text = Text::from_ansi("a\x1b[2Kb\x1b]0;title\x07c\r\td");
+        assert_eq!(text.plain(), "abc\td");
+    }
+
+    #[test]
+    fn split_at_keeps_styles() {
+        let line = Line::new(vec![Span::raw("ab"), Span::styled("cdé", red())]);
+        let (head, tail) = line.split_at(3);
+        assert_eq!(
+            head,
+            Line::new(vec![Span::raw("ab"), Span::styled("c", red())])
+        );
+        assert_eq!(tail, Line::new(vec![Span::styled("dé", red())]));
+    }
+}
@@ -0,0 +1,274 @@
+use crate::frame::rendered::{Cell, RenderedFrame};
+use std::ops::Range;
+
+/// Which way new content moves in during a wipe or slide.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+/// Effect used when a frame's content is replaced.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Transition {
+    /// Swap content immediately.
+    #[default]
+    Cut,
+    /// A moving edge uncovers the new content in place.
+    Wipe(Direction),
+    /// The new content pushes the old content out.
+    Slide(Direction),
+    /// Cells flip to the new content in random order.
+    Dissolve,
+    /// The old content disappears and the new content is typed out in reading order.
+    Typewriter,
+    /// Cells cycle through random glyphs before settling on the new content.
+    Scramble,
+}
+
+/// Glyphs shown by `Transition::Scramble` while a cell hasn't settled.
+const SCRAMBLE_GLYPHS: &[char] = &[
+    '!', '<', '>', '-', '_', '\\', '/', '[', ']', '{', '}', '=', '+', '*', '^', '?', '#', '%', '&',
+    '$', '0', '1',
+];
+
+impl Transition {
+    /// Blend `from` into `to` at `progress` (`0.0..=1.0`).
+    ///
+    /// Only cells inside `area` (columns, rows) take part; the rest are copied from `to`,
+    /// so a frame's border stays put. `blank` fills cells that belong to neither frame.
+    pub fn apply(
+        &self,
+        from: &RenderedFrame,
+        to: &RenderedFrame,
+        area: (Range<usize>, Range<usize>),
+        blank: Cell,
+        progress: f32,
+    ) -> RenderedFrame {
+        let progress = progress.clamp(0.0, 1.0);
+        let mut out = to.clone();
+        let (xs, ys) = area;
+        let (w, h) = (xs.len(), ys.len());
+        let old = |x: usize, y: usize| from.get(x, y).copied().unwrap_or(blank);
+        let new = |x: usize, y: usize| to.get(x, y).copied().unwrap_or(blank);
+
+        match *self {
+            Transition::Cut => {}
+            Transition::Wipe(dir) => {
+                for y in ys.clone() {
+                    for x in xs.clone() {
+                        let (i, j) = (x - xs.start, y - ys.start);
+                        let revealed = match dir {
+                            Direction::Left => (w - i) as f32 <= progress * w as f32,
+                            Direction::Right => (i + 1) as f32 <= progress * w as f32,
+                            Direction::Up => (h - j) as f32 <= progress * h as f32,
+                            Direction::Down => (j + 1) as f32 <= progress * h as f32,
+                        };
+                        if !revealed {
+                            out.set(x, y, old(x, y));
+                        }
+                    }
+                }
+            }
+            Transition::Slide(dir) => {
+                let dx = (progress * w as f32).round() as usize;
+                let dy = (progress * h as f32).round() as usize;
+                for y in ys.clone() {
+                    for x in xs.clone() {
+                        let (i, j) = (x - xs.start, y - ys.start);
+                        // position in a strip of old content followed by new content
+                        let cell = match dir {
+                            Direction::Left if i + dx < w => old(x + dx, y),
+                            Direction::Left => new(x + dx - w, y),
+                            Direction::Right if i >= dx => old(x - dx, y),
+                            Direction::Right => new(x + w - dx, y),
+                            Direction::Up if j + dy < h => old(x, y + dy),
+                            Direction::Up => new(x, y + dy - h),
+                            Direction::Down if j >= dy => old(x, y - dy),
+                            Direction::Down => new(x, y + h - dy),
+                        };
+                        out.set(x, y, cell);
+                    }
+                }
+            }
+            Transition::Dissolve => {
+                for y in ys.clone() {
+                    for x in xs.clone() {
+                        if unit(hash(x, y, 0)) >= progress {
+                            out.set(x, y, old(x, y));
+                        }
+                    }
+                }
+            }
+            Transition::Typewriter => {
+                let typed: Vec<(usize, usize)> = ys
+                    .clone()
+                    .flat_map(|y| xs.clone().map(move |x| (x, y)))
+                    .filter(|&(x, y)| new(x, y).ch != blank.ch)
+                    .collect();
+                let shown = (progress * typed.len() as f32).round() as usize;
+                for &(x, y) in typed.iter().skip(shown) {
+                    out.set(x, y, blank);
+                }
+                if let Some(&(x, y)) = typed.get(shown) {
+                    out.set(x, y, Cell::new('▌', new(x, y).style));
+                }
+            }
+            Transition::Scramble => {
+                // glyphs change a few times per second of progress rather than every frame
+                let tick = (progress * 24.0) as u64;
+                for y in ys.clone() {
+                    for x in xs.clone() {
+                        let (before, after) = (old(x, y), new(x, y));
+                        if unit(hash(x, y, 0)) < progress
+                            || (before.ch == blank.ch && after.ch == blank.ch)
+                        {
+                            continue;
+                        }
+                        let idx = hash(x, y, tick + 1) as usize % SCRAMBLE_GLYPHS.len();
+                        let style = if after.ch == blank.ch {
+                            before.style
+                        } else {
+                            after.style
+                        };
+                        out.set(x, y, Cell::new(SCRAMBLE_GLYPHS[idx], style));
+                    }
+                }
+            }
+        }
+        out
+    }
+}
+
+/// Cheap deterministic hash of a cell position and a seed.
+fn hash(x: usize, y: usize, seed: u64) -> u64 {
+    let mut h = (x as u64)
+        .wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        .wrapping_add((y as u64).wrapping_mul(0xC2B2_AE3D_27D4_EB4F))
+        .wrapping_add(seed.wrapping_mul(0x1656_67B1_9E37_79F9));
+    h ^= h >> 31;
+    h = h.wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    h ^ (h >> 29)
+}
+
+/// Map a hash to `0.0..1.0`.
+fn unit(h: u64) -> f32 {
+    (h >> 40) as f32 / (1u64 << 24) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frame::style::Style;
+
+    fn blank() -> Cell {
+        Cell::new(' ', Style::default())
+    }
+
+    fn frame(rows: &[&str]) -> RenderedFrame {
+        let width = rows.iter().map(|r| r.chars().count()).max().unwrap_or(0);
+        let mut frame = RenderedFrame::new(width, rows.len(), blank());
+        for (y, row) in rows.iter().enumerate() {
+            for (x, ch) in row.chars().enumerate() {
+                frame.set(x, y, Cell::new(ch, Style::default()));
+            }
+        }
+        frame
+    }
+
+    fn row(transition: Transition, from: &str, to: &str, progress: f32) -> String {
+        let (from, to) = (frame(&[from]), frame(&[to]));
+        let area = (0..to.width(), 0..1);
+        transition
+            .apply(&from, &to, area, blank(), progress)
+            .row_text(0)
+    }
+
+    const ALL: [Transition; 6] = [
+        Transition::Cut,
+        Transition::Wipe(Direction::Left),
+        Transition::Slide(Direction::Down),
+        Transition::Dissolve,
+        Transition::Typewriter,
+        Transition::Scramble,
+    ];
+
+    #[test]
+    fn every_transition_ends_on_the_new_content() {
+        let (from, to) = (frame(&["abcd", "efgh"]), frame(&["wx z", "1234"]));
+        for transition in ALL {
+            let out = transition.apply(&from, &to, (0..4, 0..2), blank(), 1.0);
+            assert_eq!(out.text(), to.text(), "{:?}", transition);
+        }
+    }
+
+    #[test]
+    fn cells_outside_the_area_are_left_alone() {
+        let (from, to) = (frame(&["#abc#"]), frame(&["|xyz|"]));
+        for transition in ALL {
+            let out = transition.apply(&from, &to, (1..4, 0..1), blank(), 0.3);
+            let text = out.row_text(0);
+            assert!(
+                text.starts_with('|') && text.ends_with('|'),
+                "{:?}",
+                transition
+            );
+        }
+    }
+
+    #[test]
+    fn wipe_and_slide_halfway() {
+        let wipe = |dir| row(Transition::Wipe(dir), "abcd", "wxyz", 0.5);
+        assert_eq!(wipe(Direction::Right), "wxcd");
+        assert_eq!(wipe(Direction::Left), "abyz");
+        let slide = |dir| row(Transition::Slide(dir), "abcd", "wxyz", 0.5);
+        assert_eq!(slide(Direction::Left), "cdwx");
+        assert_eq!(slide(Direction::Right), "yzab");
+        let (from, to) = (frame(&["a", "b"]), frame(&["y", "z"]));
+        let down = Transition::Slide(Direction::Down).apply(&from, &to, (0..1, 0..2), blank(), 0.5);
+        assert_eq!(down.text(), frame(&["z", "a"]).text());
+    }
+
+    #[test]
+    fn dissolve_only_ever_adds_new_cells() {
+        let (from, to) = (
+            frame(&["a".repeat(40).as_str()]),
+            frame(&["b".repeat(40).as_str()]),
+        );
+        let new_cells = |progress| {
+            Transition::Dissolve
+                .apply(&from, &to, (0..40, 0..1), blank(), progress)
+                .row_text(0)
+                .chars()
+                .map(|c| c == 'b')
+                .collect::<Vec<bool>>()
+        };
+        let (early, late) = (new_cells(0.3), new_cells(0.7));
+        assert!(early.iter().zip(&late).all(|(e, l)| !e || *l));
+        let count = |cells: &[bool]| cells.iter().filter(|c| **c).count();
+        assert!(count(&early) < count(&late));
+    }
+
+    #[test]
+    fn typewriter_types_in_reading_order() {
+        assert_eq!(
+            row(Transition::Typewriter, "zzzzzz", "ab  cd", 0.5),
+            "ab  ▌ "
+        );
+        assert_eq!(
+            row(Transition::Typewriter, "zzzzzz", "ab  cd", 0.0),
+            "▌     "
+        );
+    }
+
+    #[test]
+    fn scramble_leaves_blank_cells_blank() {
+        let out = row(Transition::Scramble, "a  ", "b c", 0.0);
+        let chars: Vec<char> = out.chars().collect();
+        assert!(SCRAMBLE_GLYPHS.contains(&chars[0]));
+        assert_eq!(chars[1], ' ');
+        assert!(SCRAMBLE_GLYPHS.contains(&chars[2]));
+    }
+}
@@ -0,0 +1,96 @@
+// derived from the Natural Earth 1:110m coastlines (public domain), as published at
+// http://www.gnuplotting.org/plotting-the-world-revisited
+
+/// Land mask in 2° cells: row 0 starts at 90°N, column 0 at 180°W; `#` is land.
+pub const LAND: [&str; 90] = [
+    "....................................................................................................................................................................................",
+    "....................................................................................................................................................................................",
+    "....................................................................................................................................................................................",
+    ".................................................#########.........############.....................................................................................................",
+    "..........................................#########################################......................................................##.........................................",
+    "......................................#..#.######################################...............#######..................................#####......................................",
+    ".............................###..........##..#####....#########################.................##.........................................####....................................",
+    "................................#####...#.########...........###################.....................................................##############............####.................",
+    "...........................#######..##.#####.#######..........#################..............................................#....########################......##..................",
+    "...........###..............#..##########.##.##########........################.......................###..................##############################################...........",
+    ".......#######################################.#########.......##############......................##########...........############################################################",
+    "####....###################################################....##########........................##############.####################################################################",
+    "..##..####################################################......######........#####............######.######..######################################################################",
+    "........#####################################...##....###........####........................######..###############################################################################",
+    ".......####################################........####...........###.......................#######..###################################################################..######....",
+    ".........####.......#######################........#####.#...................................######...############################################################....#...##........",
+    "..........##...........######################.......#######............................##.....#.##...###########################################################........####........",
+    "........................#########################..##########.........................###.....###...##########################################################..........###.........",
+    ".........................########################..###########.......................##.###.######################################################################......##..........",
+    "..........................####################################.........................###########################################################################..................",
+    "...........................###############################...##..........................#######################################################################.#..................",
+    "............................##############################....#..........................######################################################################..#..................",
+    "............................###############################..............................################..###################################################......................",
+    "............................###########################..............................#######...##.######......###############################################...##..................",
+    "............................##########################...............................######...#..##.#######################################################.....##..................",
+    "............................#########################................................#####..........##.##############################################...##......#...................",
+    ".............................#######################..................................###...###......#..###############################################..##...##....................",
+    "..............................######################...................................########.............##########################################...#..####....................",
+    "...............................###################....................................##########...........############################################....##.......................",
+    "................................#################....................................##############.###################################################.............................",
+    ".................................#########......##...................................#############################.####################################.............................",
+    ".................................#.######........#.................................########################.#######.##################################..............................",
+    "..................................#.#####.........................................##################################.#...#.###########################..............................",
+    ".....................................####........#................................##########################.###########....########################..#.............................",
+    ".....................................####....#....##..............................###########################.##########.....#########..########.#..................................",
+    "......................................####..##.......###..........................###########################.#########.......######.....######.#...................................",
+    "........................................######....................................############################.######..........####......#######......#.............................",
+    "............................................####.................................#############################.#####...........###.........######.....#.............................",
+    "..............................................##..................................###############################..............###.........######.....##............................",
+    "...............................................#....####..........................##############################.###............##.........#..##.......##...........................",
+    "................................................############.......................################################.............#.#........#...........##...........................",
+    "...................................................##########.......................###############################...............#.........#...........#...........................",
+    "...................................................#############.....................#####...#####################........................#.##.....##...............................",
+    "...................................................##############..............................##################..........................###....###...............................",
+    "..................................................###############..............................#################...........................###..#####.##............................",
+    "..................................................#################...........................#################.............................##...####.#.....#.......................",
+    "..................................................####################.........................###############...............................##..###.##.....#####...................",
+    ".................................................#######################........................##############................................##..............#####..#..............",
+    "..................................................#######################.......................##############.................................###.............#####................",
+    "...................................................#####################........................##############........................................#.#.......##.##.....#.........",
+    "...................................................#####################.........................#############......................................................................",
+    "....................................................###################.........................##############....#........................................###...#..................",
+    "....................................................###################.........................##############...##.....................................######...##.................",
+    "......................................................#################.........................##############..###....................................############.................",
+    ".......................................................###############..........................############....###....................................#############................",
+    ".......................................................###############...........................###########....##..................................#################.......#.......",
+    ".......................................................##############............................###########....##.................................##################...............",
+    ".......................................................###########...............................##########.....#..................................###################..............",
+    "......................................................############................................########.........................................####################.............",
+    "......................................................###########.................................########.........................................####################.............",
+    "......................................................###########..................................######...........................................###################.............",
+    "......................................................##########...................................#####............................................####.....#########..............",
+    "......................................................########......................................................................................#..........######...............",
+    ".....................................................#########..................................................................................................#####............#..",
+    ".....................................................#######.....................................................................................................................##.",
+    ".....................................................######........................................................................................................#............##..",
+    ".....................................................#####.........................................................................................................#...........##...",
+    ".....................................................####.....................................................................................................................##....",
+    "....................................................#####...........................................................................................................................",
+    "....................................................#####...........................................................................................................................",
+    "....................................................####............................................................................................................................",
+    ".....................................................###............................................................................................................................",
+    "......................................................###...........................................................................................................................",
+    "....................................................................................................................................................................................",
+    "....................................................................................................................................................................................",
+    "....................................................................................................................................................................................",
+    "....................................................................................................................................................................................",
+    "..........................................................#.........................................................................................................................",
+    "........................................................##.........................................................####..............####..##########.#############.................",
+    "........................................................##................................................##..###############....#######################################............",
+    ".....................................................######................................#################################..#################################################.....",
+    "......................................####...##......#######......................#############################################################################################.....",
+    "...................#######################################.......................############################################################################################.......",
+    "............#..######################################......................#################################################################################################........",
+    ".........#..########################################.............###....#####################################################################################################.......",
+    ".............#.########################################..###....###.....###################################################################################################.........",
+    ".............#################################################################################################################################################################......",
+    "##########..##.#####################################################################################################################################################################",
+    "####################################################################################################################################################################################",
+    "####################################################################################################################################################################################",
+];
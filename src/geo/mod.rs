@@ -0,0 +1,136 @@
+//! Embedded world data and lat/lon helpers shared by the globe and map renderers.
+
+mod land;
+
+use crate::frame::style::{Color, Style};
+use crate::frame::text::{Line, Span, Text};
+use land::LAND;
+
+const LAND_ROWS: usize = LAND.len();
+const LAND_COLS: usize = LAND[0].len();
+
+/// A position on the globe, in degrees.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LatLon {
+    pub lat: f32,
+    pub lon: f32,
+}
+
+impl LatLon {
+    pub fn new(lat: f32, lon: f32) -> Self {
+        Self { lat, lon }
+    }
+}
+
+/// Whether the point at `lat`/`lon` (degrees) is land, per the embedded low-resolution mask.
+pub fn is_land(lat: f32, lon: f32) -> bool {
+    let row = ((90.0 - lat) / 180.0 * LAND_ROWS as f32).floor() as isize;
+    let row = row.clamp(0, LAND_ROWS as isize - 1) as usize;
+    let col = ((normalize_lon(lon) + 180.0) / 360.0 * LAND_COLS as f32).floor() as usize;
+    LAND[row].as_bytes()[col.min(LAND_COLS - 1)] == b'#'
+}
+
+/// Wrap a longitude into `-180.0..180.0`.
+pub fn normalize_lon(lon: f32) -> f32 {
+    (lon + 180.0).rem_euclid(360.0) - 180.0
+}
+
+/// A labelled point of interest, drawn by the globe and map renderers.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Marker {
+    pub position: LatLon,
+    pub glyph: char,
+    pub style: Style,
+    pub label: Option<String>,
+}
+
+impl Marker {
+    pub fn new(lat: f32, lon: f32) -> Self {
+        Self {
+            position: LatLon::new(lat, lon),
+            glyph: '●',
+            style: Style::default(),
+            label: None,
+        }
+    }
+
+    pub fn with_glyph(mut self, glyph: char) -> Self {
+        self.glyph = glyph;
+        self
+    }
+
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.style = self.style.with_fg(color);
+        self
+    }
+
+    pub fn with_label(mut self, label: &str) -> Self {
+        self.label = Some(label.to_owned());
+        self
+    }
+}
+
+/// A grid of styled characters that markers are drawn onto.
+pub(crate) type Canvas = Vec<Vec<(char, Style)>>;
+
+/// Draw `marker` at (`col`, `row`) with its label to the right, or to the left if it
+/// would run off the canvas.
+pub(crate) fn place_marker(canvas: &mut Canvas, marker: &Marker, col: usize, row: usize) {
+    let Some(line) = canvas.get_mut(row) else {
+        return;
+    };
+    let width = line.len();
+    if col >= width {
+        return;
+    }
+    line[col] = (marker.glyph, marker.style);
+    if let Some(label) = &marker.label {
+        let len = label.chars().count();
+        let start = if col + 2 + len <= width || col < len + 1 {
+            col + 2
+        } else {
+            col - len - 1
+        };
+        for (i, ch) in label.chars().enumerate() {
+            if let Some(cell) = line.get_mut(start + i) {
+                *cell = (ch, marker.style);
+            }
+        }
+    }
+}
+
+/// Plain text of a canvas, with trailing spaces trimmed.
+pub(crate) fn canvas_string(canvas: &Canvas) -> String {
+    canvas
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|(c, _)| c)
+                .collect::<String>()
+                .trim_end()
+                .to_owned()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Styled text of a canvas.
+pub(crate) fn canvas_text(canvas: &Canvas) -> Text {
+    let mut text = Text::default();
+    for row in canvas {
+        let mut line = Line::default();
+        for (ch, style) in row {
+            match line.spans.last_mut() {
+                Some(span) if span.style == *style => span.text.push(*ch),
+                _ => line.push(Span::styled(&ch.to_string(), *style)),
+            }
+        }
+        text.push(line);
+    }
+    text
+}
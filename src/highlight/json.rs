@@ -0,0 +1,40 @@
+use super::{Grammar, Scanner, Token, TokenKind};
+
+/// JSON documents.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Json;
+
+impl Grammar for Json {
+    fn name(&self) -> &str {
+        "json"
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["json"]
+    }
+
+    fn tokenize(&self, source: &str) -> Vec<Token> {
+        let mut s = Scanner::new(source);
+        while let Some(ch) = s.peek() {
+            let start = s.pos();
+            if s.eat('"') {
+                s.eat_quoted('"', true);
+                // a string followed by `:` is an object key
+                let kind = if s.rest().trim_start().starts_with(':') {
+                    TokenKind::Key
+                } else {
+                    TokenKind::String
+                };
+                s.token(kind, start);
+            } else if ch.is_ascii_digit() || ch == '-' {
+                s.eat_while(|c| c.is_ascii_digit() || "+-.eE".contains(c));
+                s.token(TokenKind::Number, start);
+            } else if ["true", "false", "null"].iter().any(|w| s.eat_str(w)) {
+                s.token(TokenKind::Constant, start);
+            } else {
+                s.bump();
+            }
+        }
+        s.finish()
+    }
+}
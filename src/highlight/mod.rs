@@ -0,0 +1,408 @@
+//! Syntax highlighting: grammars split source code into tokens, and a theme turns them
+//! into styled frame content.
+
+mod json;
+mod rust;
+mod scanner;
+mod shell;
+mod toml;
+
+pub use json::Json;
+pub use rust::Rust;
+pub use scanner::Scanner;
+pub use shell::Shell;
+pub use toml::Toml;
+
+use crate::frame::style::{Color, Style};
+use crate::frame::text::{Line, Span, Text};
+use std::collections::HashMap;
+use std::ops::Range;
+use std::path::Path;
+
+/// What a token is, for picking its color.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TokenKind {
+    Keyword,
+    Type,
+    Function,
+    Macro,
+    String,
+    Number,
+    /// `true`, `false`, `null` and constant names.
+    Constant,
+    Comment,
+    /// Rust attributes, TOML table headers.
+    Attribute,
+    /// Keys of TOML and JSON tables.
+    Key,
+    /// Shell variables, Rust lifetimes.
+    Variable,
+}
+
+/// A highlighted piece of source: `range` is in bytes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub range: Range<usize>,
+}
+
+/// Tokenizer for one language.
+pub trait Grammar: Send + Sync {
+    /// Name for picking the grammar explicitly, e.g. `rust`.
+    fn name(&self) -> &str;
+    /// File extensions the grammar is used for, without the dot.
+    fn extensions(&self) -> &[&str];
+    /// Tokens of `source` worth coloring, in order and not overlapping; everything
+    /// between them is plain.
+    fn tokenize(&self, source: &str) -> Vec<Token>;
+}
+
+/// Styles for each kind of token; kinds without a style are drawn plain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Theme {
+    styles: HashMap<TokenKind, Style>,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::empty()
+            .with_style(TokenKind::Keyword, Style::new().with_fg(Color::Magenta))
+            .with_style(TokenKind::Type, Style::new().with_fg(Color::Yellow))
+            .with_style(TokenKind::Function, Style::new().with_fg(Color::Blue))
+            .with_style(TokenKind::Macro, Style::new().with_fg(Color::Cyan))
+            .with_style(TokenKind::String, Style::new().with_fg(Color::Green))
+            .with_style(TokenKind::Number, Style::new().with_fg(Color::DarkYellow))
+            .with_style(TokenKind::Constant, Style::new().with_fg(Color::DarkYellow))
+            .with_style(
+                TokenKind::Comment,
+                Style::new().with_fg(Color::DarkGrey).with_italic(true),
+            )
+            .with_style(TokenKind::Attribute, Style::new().with_fg(Color::DarkCyan))
+            .with_style(TokenKind::Key, Style::new().with_fg(Color::Blue))
+            .with_style(TokenKind::Variable, Style::new().with_fg(Color::Red))
+    }
+}
+
+impl Theme {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A theme that leaves everything plain.
+    pub fn empty() -> Self {
+        Self {
+            styles: HashMap::new(),
+        }
+    }
+
+    pub fn with_style(mut self, kind: TokenKind, style: Style) -> Self {
+        self.styles.insert(kind, style);
+        self
+    }
+
+    pub fn style(&self, kind: TokenKind) -> Style {
+        self.styles.get(&kind).copied().unwrap_or_default()
+    }
+}
+
+/// Picks a grammar for a file and turns its source into styled content.
+pub struct Highlighter {
+    grammars: Vec<Box<dyn Grammar>>,
+    theme: Theme,
+}
+
+impl Default for Highlighter {
+    fn default() -> Self {
+        Self {
+            grammars: vec![
+                Box::new(Rust),
+                Box::new(Toml),
+                Box::new(Json),
+                Box::new(Shell),
+            ],
+            theme: Theme::default(),
+        }
+    }
+}
+
+impl Highlighter {
+    /// A highlighter with the built-in grammars and the default theme.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a grammar; it's preferred over earlier ones for the same extension.
+    pub fn with_grammar(mut self, grammar: impl Grammar + 'static) -> Self {
+        self.grammars.insert(0, Box::new(grammar));
+        self
+    }
+
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    pub fn grammars(&self) -> impl Iterator<Item = &dyn Grammar> {
+        self.grammars.iter().map(|g| g.as_ref())
+    }
+
+    /// The grammar called `name`, or used for extension `name`; case doesn't matter.
+    pub fn grammar(&self, name: &str) -> Option<&dyn Grammar> {
+        let name = name.to_ascii_lowercase();
+        self.grammars()
+            .find(|g| g.name() == name)
+            .or_else(|| self.grammars().find(|g| g.extensions().contains(&&*name)))
+    }
+
+    /// The grammar for `path`, by its extension.
+    pub fn grammar_for(&self, path: &Path) -> Option<&dyn Grammar> {
+        let ext = path.extension()?.to_str()?.to_ascii_lowercase();
+        self.grammars().find(|g| g.extensions().contains(&&*ext))
+    }
+
+    /// `source` as frame content, colored by `grammar`'s tokens.
+    pub fn highlight(&self, grammar: &dyn Grammar, source: &str) -> Text {
+        let mut text = Text::default();
+        let mut line = Line::default();
+        let mut push = |piece: &str, style: Style| {
+            for (i, part) in piece.split('\n').enumerate() {
+                if i > 0 {
+                    text.push(std::mem::take(&mut line));
+                }
+                // leave `\r` of CRLF line endings out of the content
+                let part = part.strip_suffix('\r').unwrap_or(part);
+                if !part.is_empty() {
+                    line.push(Span::styled(part, style));
+                }
+            }
+        };
+        let mut pos = 0;
+        for token in grammar.tokenize(source) {
+            let Range { start, end } = token.range;
+            // grammars are pluggable, so skip tokens that would slice out of bounds or
+            // inside a character
+            if start < pos
+                || start > end
+                || !source.is_char_boundary(start)
+                || !source.is_char_boundary(end)
+            {
+                continue;
+            }
+            push(&source[pos..start], Style::default());
+            push(&source[start..end], self.theme.style(token.kind));
+            pos = end;
+        }
+        push(&source[pos..], Style::default());
+        if !source.is_empty() && !source.ends_with('\n') {
+            text.push(line);
+        }
+        text
+    }
+
+    /// `source` of the file at `path` as frame content. If no grammar matches, ANSI
+    /// colors in it are applied instead; see `Text::from_ansi`.
+    pub fn highlight_path(&self, path: &Path, source: &str) -> Text {
+        match self.grammar_for(path) {
+            Some(grammar) => self.highlight(grammar, source),
+            None => Text::from_ansi(source),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use TokenKind::*;
+
+    /// Each token as its kind and source text.
+    fn tokens<'a>(grammar: &dyn Grammar, source: &'a str) -> Vec<(TokenKind, &'a str)> {
+        grammar
+            .tokenize(source)
+            .into_iter()
+            .map(|token| (token.kind, &source[token.range]))
+            .collect()
+    }
+
+    #[test]
+    fn rust() {
+        let source = "#[derive(Debug)]\nfn main() { let s: &str = \"hi\\\"\"; println!(\"{}\", 1.5); } // done";
+        assert_eq!(
+            tokens(&Rust, source),
+            [
+                (Attribute, "#[derive(Debug)]"),
+                (Keyword, "fn"),
+                (Function, "main"),
+                (Keyword, "let"),
+                (Type, "str"),
+                (String, "\"hi\\\"\""),
+                (Macro, "println!"),
+                (String, "\"{}\""),
+                (Number, "1.5"),
+                (Comment, "// done"),
+            ]
+        );
+    }
+
+    #[test]
+    fn rust_literals_and_lifetimes() {
+        let source = "'a' b'\\n' &'static r#\"raw \"q\"\"# /* a /* nested */ c */ 0..n MAX_LEN Vec";
+        assert_eq!(
+            tokens(&Rust, source),
+            [
+                (String, "'a'"),
+                (String, "b'\\n'"),
+                (Variable, "'static"),
+                (String, "r#\"raw \"q\"\"#"),
+                (Comment, "/* a /* nested */ c */"),
+                (Number, "0"),
+                (Constant, "MAX_LEN"),
+                (Type, "Vec"),
+            ]
+        );
+    }
+
+    #[test]
+    fn toml() {
+        let source = "# top\n[package]\nname = \"x\" # c\nv = [1, 'a']\nt = { k = true }\n[[bin]]";
+        assert_eq!(
+            tokens(&Toml, source),
+            [
+                (Comment, "# top"),
+                (Attribute, "[package]"),
+                (Key, "name"),
+                (String, "\"x\""),
+                (Comment, "# c"),
+                (Key, "v"),
+                (Number, "1"),
+                (String, "'a'"),
+                (Key, "t"),
+                (Key, "k"),
+                (Constant, "true"),
+                (Attribute, "[[bin]]"),
+            ]
+        );
+    }
+
+    #[test]
+    fn toml_multiline_array() {
+        let source = "a = [\n  \"x\",\n]\nb = 2";
+        assert_eq!(
+            tokens(&Toml, source),
+            [(Key, "a"), (String, "\"x\""), (Key, "b"), (Number, "2")]
+        );
+    }
+
+    #[test]
+    fn json() {
+        let source = r#"{"a": [1, -2.5e3, "s\"x"], "b": null, "c": true}"#;
+        assert_eq!(
+            tokens(&Json, source),
+            [
+                (Key, "\"a\""),
+                (Number, "1"),
+                (Number, "-2.5e3"),
+                (String, r#""s\"x""#),
+                (Key, "\"b\""),
+                (Constant, "null"),
+                (Key, "\"c\""),
+                (Constant, "true"),
+            ]
+        );
+    }
+
+    #[test]
+    fn shell() {
+        let source = "# c\nif [ \"$HOME\" ]; then echo a#b '${x}' ${y:-z} $?; fi";
+        assert_eq!(
+            tokens(&Shell, source),
+            [
+                (Comment, "# c"),
+                (Keyword, "if"),
+                (String, "\""),
+                (Variable, "$HOME"),
+                (String, "\""),
+                (Keyword, "then"),
+                (String, "'${x}'"),
+                (Variable, "${y:-z}"),
+                (Variable, "$?"),
+                (Keyword, "fi"),
+            ]
+        );
+    }
+
+    #[test]
+    fn grammar_lookup() {
+        let highlighter = Highlighter::new();
+        assert_eq!(highlighter.grammar("RUST").unwrap().name(), "rust");
+        assert_eq!(highlighter.grammar("bash").unwrap().name(), "sh");
+        assert_eq!(
+            highlighter
+                .grammar_for(Path::new("a/Cargo.TOML"))
+                .unwrap()
+                .name(),
+            "toml"
+        );
+        assert!(highlighter.grammar_for(Path::new("README")).is_none());
+    }
+
+    #[test]
+    fn highlight_splits_lines() {
+        let highlighter = Highlighter::new()
+            .with_theme(Theme::empty().with_style(Comment, Style::new().with_fg(Color::Red)));
+        let text = highlighter.highlight(&Rust, "a /* x\r\ny */\n");
+        let red = Style::new().with_fg(Color::Red);
+        assert_eq!(
+            text.lines,
+            [
+                Line::new(vec![Span::raw("a "), Span::styled("/* x", red)]),
+                Line::new(vec![Span::styled("y */", red)]),
+            ]
+        );
+    }
+
+    /// Returns whatever ranges it was built with.
+    struct Broken(Vec<Range<usize>>);
+
+    impl Grammar for Broken {
+        fn name(&self) -> &str {
+            "broken"
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &[]
+        }
+
+        fn tokenize(&self, _source: &str) -> Vec<Token> {
+            self.0
+                .iter()
+                .map(|range| Token {
+                    kind: Keyword,
+                    range: range.clone(),
+                })
+                .collect()
+        }
+    }
+
+    #[test]
+    fn highlight_skips_invalid_tokens() {
+        #[allow(clippy::reversed_empty_ranges)]
+        let grammar = Broken(vec![3..1, 1..2, 4..6, 0..2, 6..99, 6..7]);
+        let text = Highlighter::new().highlight(&grammar, "héllo!");
+        assert_eq!(text.plain(), "héllo!");
+        let keyword = Theme::default().style(Keyword);
+        assert_eq!(
+            text.lines[0],
+            Line::new(vec![
+                Span::raw("hél"),
+                Span::styled("lo", keyword),
+                Span::styled("!", keyword),
+            ])
+        );
+    }
+
+    #[test]
+    fn highlight_path_falls_back_to_ansi() {
+        let text = Highlighter::new().highlight_path(Path::new("log"), "\x1b[31mred\x1b[0m");
+        assert_eq!(text.plain(), "red");
+    }
+}
@@ -0,0 +1,180 @@
+use super::scanner::{is_ident, is_ident_start};
+use super::{Grammar, Scanner, Token, TokenKind};
+
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref",
+    "return", "self", "Self", "static", "struct", "super", "trait", "type", "union", "unsafe",
+    "use", "where", "while", "yield",
+];
+
+const PRIMITIVES: &[&str] = &[
+    "bool", "char", "str", "f32", "f64", "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16",
+    "u32", "u64", "u128", "usize",
+];
+
+/// Rust source.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Rust;
+
+impl Grammar for Rust {
+    fn name(&self) -> &str {
+        "rust"
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["rs"]
+    }
+
+    fn tokenize(&self, source: &str) -> Vec<Token> {
+        let mut s = Scanner::new(source);
+        while let Some(ch) = s.peek() {
+            let start = s.pos();
+            if s.eat_str("//") {
+                s.eat_line();
+                s.token(TokenKind::Comment, start);
+            } else if s.eat_str("/*") {
+                block_comment(&mut s);
+                s.token(TokenKind::Comment, start);
+            } else if s.eat_str("#[") || s.eat_str("#![") {
+                attribute(&mut s);
+                s.token(TokenKind::Attribute, start);
+            } else if raw_string(&mut s) {
+                s.token(TokenKind::String, start);
+            } else if s.eat('"') || s.eat_str("b\"") || s.eat_str("c\"") {
+                s.eat_quoted('"', true);
+                s.token(TokenKind::String, start);
+            } else if ch == '\'' || s.rest().starts_with("b'") {
+                let kind = char_or_lifetime(&mut s);
+                s.token(kind, start);
+            } else if ch.is_ascii_digit() {
+                number(&mut s);
+                s.token(TokenKind::Number, start);
+            } else if is_ident_start(ch) {
+                s.eat_while(is_ident);
+                let kind = ident_kind(&s, s.since(start));
+                if kind == Some(TokenKind::Macro) {
+                    s.eat('!');
+                }
+                if let Some(kind) = kind {
+                    s.token(kind, start);
+                }
+            } else {
+                s.bump();
+            }
+        }
+        s.finish()
+    }
+}
+
+/// The rest of a block comment, which may nest.
+fn block_comment(s: &mut Scanner) {
+    let mut depth = 1;
+    while depth > 0 && !s.is_done() {
+        if s.eat_str("/*") {
+            depth += 1;
+        } else if s.eat_str("*/") {
+            depth -= 1;
+        } else {
+            s.bump();
+        }
+    }
+}
+
+/// The rest of an attribute, up to its matching `]`.
+fn attribute(s: &mut Scanner) {
+    let mut depth = 1;
+    while let Some(ch) = s.bump() {
+        match ch {
+            '[' => depth += 1,
+            ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    break;
+                }
+            }
+            '"' => s.eat_quoted('"', true),
+            _ => {}
+        }
+    }
+}
+
+/// A raw string like `r#"..."#`, if one is next.
+fn raw_string(s: &mut Scanner) -> bool {
+    let rest = s.rest();
+    let prefix = if rest.starts_with("br") || rest.starts_with("cr") {
+        2
+    } else if rest.starts_with('r') {
+        1
+    } else {
+        return false;
+    };
+    let hashes = rest[prefix..].chars().take_while(|&c| c == '#').count();
+    if !rest[prefix + hashes..].starts_with('"') {
+        return false;
+    }
+    s.eat_str(&rest[..prefix + hashes + 1]);
+    s.eat_until(&format!("\"{}", "#".repeat(hashes)));
+    true
+}
+
+/// A character literal like `'a'` or `b'\n'`, or a lifetime like `'a`.
+fn char_or_lifetime(s: &mut Scanner) -> TokenKind {
+    s.eat('b');
+    s.eat('\'');
+    let rest = s.rest();
+    let mut chars = rest.chars();
+    match (chars.next(), chars.next()) {
+        (Some('\\'), _) => {
+            s.eat_quoted('\'', true);
+            TokenKind::String
+        }
+        (Some(c), Some('\'')) if c != '\'' => {
+            s.bump();
+            s.bump();
+            TokenKind::String
+        }
+        _ => {
+            s.eat_while(is_ident);
+            TokenKind::Variable
+        }
+    }
+}
+
+fn number(s: &mut Scanner) {
+    loop {
+        s.eat_while(|c| c.is_ascii_alphanumeric() || c == '_');
+        // a fraction, but not a range like `0..n` or a method like `1.max(2)`
+        if s.peek() == Some('.') && s.peek_nth(1).is_some_and(|c| c.is_ascii_digit()) {
+            s.bump();
+        } else {
+            break;
+        }
+    }
+}
+
+/// How an identifier is highlighted, from the word and what follows it.
+fn ident_kind(s: &Scanner, word: &str) -> Option<TokenKind> {
+    let next = s.rest().trim_start_matches([' ', '\t']);
+    if KEYWORDS.contains(&word) {
+        Some(TokenKind::Keyword)
+    } else if word == "true" || word == "false" {
+        Some(TokenKind::Constant)
+    } else if next.starts_with('!') && !next.starts_with("!=") {
+        Some(TokenKind::Macro)
+    } else if PRIMITIVES.contains(&word) {
+        Some(TokenKind::Type)
+    } else if word.len() > 1
+        && word
+            .chars()
+            .all(|c| c.is_uppercase() || c == '_' || c.is_ascii_digit())
+    {
+        Some(TokenKind::Constant)
+    } else if word.starts_with(char::is_uppercase) {
+        Some(TokenKind::Type)
+    } else if next.starts_with('(') || next.starts_with("::<") {
+        Some(TokenKind::Function)
+    } else {
+        None
+    }
+}
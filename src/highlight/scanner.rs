@@ -0,0 +1,137 @@
+use super::{Token, TokenKind};
+
+/// Cursor over source code for writing grammars: consume characters and record tokens.
+#[derive(Clone, Debug)]
+pub struct Scanner<'a> {
+    source: &'a str,
+    pos: usize,
+    tokens: Vec<Token>,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            pos: 0,
+            tokens: vec![],
+        }
+    }
+
+    /// Byte offset of the next character.
+    pub fn pos(&self) -> usize {
+        self.pos
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.pos >= self.source.len()
+    }
+
+    /// The source not consumed yet.
+    pub fn rest(&self) -> &'a str {
+        &self.source[self.pos..]
+    }
+
+    /// The source from `start` up to the current position.
+    pub fn since(&self, start: usize) -> &'a str {
+        &self.source[start..self.pos]
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    /// The character `n` places after the next one.
+    pub fn peek_nth(&self, n: usize) -> Option<char> {
+        self.rest().chars().nth(n)
+    }
+
+    /// The last character consumed, if any.
+    pub fn prev(&self) -> Option<char> {
+        self.source[..self.pos].chars().next_back()
+    }
+
+    pub fn bump(&mut self) -> Option<char> {
+        let ch = self.peek()?;
+        self.pos += ch.len_utf8();
+        Some(ch)
+    }
+
+    /// Consume `ch` if it's next.
+    pub fn eat(&mut self, ch: char) -> bool {
+        if self.peek() == Some(ch) {
+            self.pos += ch.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consume `s` if it's next.
+    pub fn eat_str(&mut self, s: &str) -> bool {
+        if self.rest().starts_with(s) {
+            self.pos += s.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consume characters while `pred` holds; returns whether any were consumed.
+    pub fn eat_while(&mut self, pred: impl Fn(char) -> bool) -> bool {
+        let start = self.pos;
+        while self.peek().is_some_and(&pred) {
+            self.bump();
+        }
+        self.pos > start
+    }
+
+    /// Consume up to and including `end`, or to the end of the source.
+    pub fn eat_until(&mut self, end: &str) {
+        match self.rest().find(end) {
+            Some(i) => self.pos += i + end.len(),
+            None => self.pos = self.source.len(),
+        }
+    }
+
+    /// Consume the rest of the line, leaving the newline.
+    pub fn eat_line(&mut self) {
+        self.eat_while(|c| c != '\n');
+    }
+
+    /// Consume a quoted string whose opening `quote` was just consumed, honoring
+    /// backslash escapes if `escapes` is set.
+    pub fn eat_quoted(&mut self, quote: char, escapes: bool) {
+        while let Some(ch) = self.bump() {
+            if ch == quote {
+                break;
+            }
+            if ch == '\\' && escapes {
+                self.bump();
+            }
+        }
+    }
+
+    /// Record a token from `start` to the current position.
+    pub fn token(&mut self, kind: TokenKind, start: usize) {
+        if self.pos > start {
+            self.tokens.push(Token {
+                kind,
+                range: start..self.pos,
+            });
+        }
+    }
+
+    pub fn finish(self) -> Vec<Token> {
+        self.tokens
+    }
+}
+
+/// Whether `ch` can start an identifier.
+pub(crate) fn is_ident_start(ch: char) -> bool {
+    ch.is_alphabetic() || ch == '_'
+}
+
+/// Whether `ch` can continue an identifier.
+pub(crate) fn is_ident(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '_'
+}
@@ -0,0 +1,104 @@
+use super::scanner::is_ident;
+use super::{Grammar, Scanner, Token, TokenKind};
+
+const KEYWORDS: &[&str] = &[
+    "if", "then", "else", "elif", "fi", "for", "while", "until", "do", "done", "case", "esac",
+    "in", "function", "select", "return", "local", "export", "readonly", "declare", "unset",
+];
+
+/// POSIX shell and bash scripts.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Shell;
+
+impl Grammar for Shell {
+    fn name(&self) -> &str {
+        "sh"
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["sh", "bash", "zsh", "ksh"]
+    }
+
+    fn tokenize(&self, source: &str) -> Vec<Token> {
+        let mut s = Scanner::new(source);
+        while let Some(ch) = s.peek() {
+            let start = s.pos();
+            // `#` starts a comment only at the start of a word
+            let word_start = s.prev().is_none_or(|c| !is_ident(c) && c != '$');
+            match ch {
+                '#' if word_start => {
+                    s.eat_line();
+                    s.token(TokenKind::Comment, start);
+                }
+                '\'' => {
+                    s.bump();
+                    s.eat_quoted('\'', false);
+                    s.token(TokenKind::String, start);
+                }
+                '"' => {
+                    s.bump();
+                    double_quoted(&mut s);
+                }
+                '$' => {
+                    variable(&mut s);
+                    s.token(TokenKind::Variable, start);
+                }
+                '\\' => {
+                    s.bump();
+                    s.bump();
+                }
+                _ if is_ident(ch) && word_start => {
+                    s.eat_while(|c| is_ident(c) || c == '-');
+                    if KEYWORDS.contains(&s.since(start)) {
+                        s.token(TokenKind::Keyword, start);
+                    }
+                }
+                _ => {
+                    s.bump();
+                }
+            }
+        }
+        s.finish()
+    }
+}
+
+/// The rest of a double-quoted string, with the variables in it highlighted as such.
+fn double_quoted(s: &mut Scanner) {
+    let mut start = s.pos() - 1;
+    while let Some(ch) = s.peek() {
+        match ch {
+            '"' => {
+                s.bump();
+                break;
+            }
+            '\\' => {
+                s.bump();
+                s.bump();
+            }
+            '$' => {
+                s.token(TokenKind::String, start);
+                let var = s.pos();
+                variable(s);
+                s.token(TokenKind::Variable, var);
+                start = s.pos();
+            }
+            _ => {
+                s.bump();
+            }
+        }
+    }
+    s.token(TokenKind::String, start);
+}
+
+/// A variable like `$HOME`, `${name:-default}`, `$1` or `$?`.
+fn variable(s: &mut Scanner) {
+    s.eat('$');
+    if s.eat('{') {
+        s.eat_while(|c| c != '}' && c != '\n');
+        s.eat('}');
+    } else if s.eat('(') {
+        // command substitution: highlight just the `$(`
+    } else if !s.eat_while(is_ident) && s.peek().is_some_and(|c| "?!#*@$-".contains(c)) {
+        s.bump();
+    }
+}
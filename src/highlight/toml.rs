@@ -0,0 +1,113 @@
+use super::{Grammar, Scanner, Token, TokenKind};
+
+/// TOML documents, like `Cargo.toml`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Toml;
+
+impl Grammar for Toml {
+    fn name(&self) -> &str {
+        "toml"
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["toml"]
+    }
+
+    fn tokenize(&self, source: &str) -> Vec<Token> {
+        let mut s = Scanner::new(source);
+        // open arrays and inline tables; keys start lines outside them, or follow `{`
+        // and `,` in an inline table
+        let mut open: Vec<char> = vec![];
+        let mut expect_key = true;
+        while let Some(ch) = s.peek() {
+            let start = s.pos();
+            match ch {
+                '#' => {
+                    s.eat_line();
+                    s.token(TokenKind::Comment, start);
+                }
+                '\n' => {
+                    s.bump();
+                    expect_key = open.is_empty();
+                }
+                ' ' | '\t' | '\r' => {
+                    s.bump();
+                }
+                '[' if expect_key && open.is_empty() => {
+                    let double = s.eat_str("[[");
+                    s.eat_while(|c| c != ']' && c != '\n');
+                    s.eat(']');
+                    if double {
+                        s.eat(']');
+                    }
+                    s.token(TokenKind::Attribute, start);
+                    expect_key = false;
+                }
+                '[' | '{' => {
+                    s.bump();
+                    open.push(ch);
+                    expect_key = ch == '{';
+                }
+                ']' | '}' => {
+                    s.bump();
+                    open.pop();
+                }
+                ',' => {
+                    s.bump();
+                    expect_key = open.last() == Some(&'{');
+                }
+                _ if expect_key => {
+                    key(&mut s);
+                    s.token(TokenKind::Key, start);
+                    expect_key = false;
+                }
+                _ => {
+                    if let Some(kind) = value(&mut s) {
+                        s.token(kind, start);
+                    }
+                }
+            }
+        }
+        s.finish()
+    }
+}
+
+/// A key, possibly dotted and quoted, up to the space or `=` after it.
+fn key(s: &mut Scanner) {
+    while let Some(ch) = s.peek() {
+        match ch {
+            '"' | '\'' => {
+                s.bump();
+                s.eat_quoted(ch, ch == '"');
+            }
+            '=' | ' ' | '\t' | '\r' | '\n' | '#' => break,
+            _ => {
+                s.bump();
+            }
+        }
+    }
+}
+
+/// A value, or a single character of punctuation.
+fn value(s: &mut Scanner) -> Option<TokenKind> {
+    let ch = s.peek()?;
+    if s.eat_str("\"\"\"") {
+        s.eat_until("\"\"\"");
+        Some(TokenKind::String)
+    } else if s.eat_str("'''") {
+        s.eat_until("'''");
+        Some(TokenKind::String)
+    } else if s.eat('"') || s.eat('\'') {
+        s.eat_quoted(ch, ch == '"');
+        Some(TokenKind::String)
+    } else if ch.is_ascii_digit() || ch == '+' || ch == '-' {
+        // numbers, dates and times
+        s.eat_while(|c| c.is_ascii_alphanumeric() || "+-_.:".contains(c));
+        Some(TokenKind::Number)
+    } else if ["true", "false", "inf", "nan"].iter().any(|w| s.eat_str(w)) {
+        Some(TokenKind::Constant)
+    } else {
+        s.bump();
+        None
+    }
+}
@@ -0,0 +1,123 @@
+use super::{luminance, Image};
+use crate::frame::style::{Color, Style};
+use crate::frame::text::{Line, Span, Text};
+
+/// Characters from darkest to brightest.
+pub const DEFAULT_RAMP: &str = " .:-=+*#%@";
+
+/// Options for turning an image into frame content.
+#[derive(Clone, Debug)]
+pub struct ConvertOptions {
+    /// Characters from darkest to brightest, for ASCII output.
+    pub ramp: Vec<char>,
+    /// Map bright pixels to the start of the ramp (for dark-on-light terminals).
+    pub invert: bool,
+    /// Width of a terminal cell divided by its height.
+    pub cell_aspect: f32,
+    /// Keep the image's aspect ratio instead of stretching it over the whole area.
+    pub keep_aspect: bool,
+}
+
+impl Default for ConvertOptions {
+    fn default() -> Self {
+        Self {
+            ramp: DEFAULT_RAMP.chars().collect(),
+            invert: false,
+            cell_aspect: 0.5,
+            keep_aspect: true,
+        }
+    }
+}
+
+impl ConvertOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_ramp(mut self, ramp: &str) -> Self {
+        self.ramp = ramp.chars().collect();
+        self
+    }
+
+    pub fn with_invert(mut self, invert: bool) -> Self {
+        self.invert = invert;
+        self
+    }
+
+    pub fn with_cell_aspect(mut self, cell_aspect: f32) -> Self {
+        self.cell_aspect = cell_aspect;
+        self
+    }
+
+    pub fn with_keep_aspect(mut self, keep_aspect: bool) -> Self {
+        self.keep_aspect = keep_aspect;
+        self
+    }
+
+    fn size(&self, image: &Image, cols: usize, rows: usize) -> (usize, usize) {
+        if self.keep_aspect {
+            image.fit(cols, rows, self.cell_aspect)
+        } else {
+            (cols, rows)
+        }
+    }
+}
+
+impl Image {
+    /// Luminance-ramp ASCII art filling at most `cols` x `rows` cells,
+    /// e.g. `content_width()` x `content_height()` of a frame.
+    pub fn to_ascii(&self, cols: usize, rows: usize) -> String {
+        self.to_ascii_with(cols, rows, &ConvertOptions::default())
+    }
+
+    pub fn to_ascii_with(&self, cols: usize, rows: usize, options: &ConvertOptions) -> String {
+        let (cols, rows) = options.size(self, cols, rows);
+        let ramp = &options.ramp;
+        if ramp.is_empty() || cols == 0 || rows == 0 {
+            return String::new();
+        }
+        let small = self.resize(cols, rows);
+        (0..rows)
+            .map(|y| {
+                (0..cols)
+                    .map(|x| {
+                        let mut l = luminance(small.pixel(x, y));
+                        if options.invert {
+                            l = 1.0 - l;
+                        }
+                        let idx = (l * (ramp.len() - 1) as f32).round() as usize;
+                        ramp[idx.min(ramp.len() - 1)]
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    /// Truecolor half-block art: each cell is `▀` with the upper pixel as foreground
+    /// and the lower pixel as background, doubling the vertical resolution.
+    pub fn to_half_blocks(&self, cols: usize, rows: usize) -> Text {
+        self.to_half_blocks_with(cols, rows, &ConvertOptions::default())
+    }
+
+    pub fn to_half_blocks_with(&self, cols: usize, rows: usize, options: &ConvertOptions) -> Text {
+        let (cols, rows) = options.size(self, cols, rows);
+        if cols == 0 || rows == 0 {
+            return Text::default();
+        }
+        let small = self.resize(cols, rows * 2);
+        let rgb = |(r, g, b)| Color::Rgb { r, g, b };
+        let mut text = Text::default();
+        for y in 0..rows {
+            let mut line = Line::default();
+            for x in 0..cols {
+                let style = Style::new()
+                    .with_fg(rgb(small.pixel(x, y * 2)))
+                    .with_bg(rgb(small.pixel(x, y * 2 + 1)));
+                line.push(Span::styled("▀", style));
+            }
+            text.push(line);
+        }
+        text
+    }
+}
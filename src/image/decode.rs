@@ -0,0 +1,221 @@
+use super::{Image, Rgb};
+use anyhow::{bail, Context};
+use png::{ColorType, Decoder, Transformations};
+
+pub const PNG_SIGNATURE: &[u8] = &[0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+
+/// Decode a PNG; alpha is composited over black.
+pub fn png(bytes: &[u8]) -> anyhow::Result<Image> {
+    let mut decoder = Decoder::new(bytes);
+    decoder.set_transformations(Transformations::normalize_to_color8());
+    let mut reader = decoder.read_info()?;
+    let mut buf = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut buf)?;
+    let buf = &buf[..info.buffer_size()];
+    let over_black = |c: u8, a: u8| (c as u16 * a as u16 / 255) as u8;
+    let pixels: Vec<Rgb> = match info.color_type {
+        ColorType::Rgb => buf.chunks_exact(3).map(|p| (p[0], p[1], p[2])).collect(),
+        ColorType::Rgba => buf
+            .chunks_exact(4)
+            .map(|p| {
+                (
+                    over_black(p[0], p[3]),
+                    over_black(p[1], p[3]),
+                    over_black(p[2], p[3]),
+                )
+            })
+            .collect(),
+        ColorType::Grayscale => buf.iter().map(|v| (*v, *v, *v)).collect(),
+        ColorType::GrayscaleAlpha => buf
+            .chunks_exact(2)
+            .map(|p| {
+                let v = over_black(p[0], p[1]);
+                (v, v, v)
+            })
+            .collect(),
+        ColorType::Indexed => bail!("indexed PNG was not expanded"),
+    };
+    Image::from_rgb(info.width as usize, info.height as usize, pixels)
+}
+
+/// Decode a binary or plain-text PPM (`P6`/`P3`) or PGM (`P5`/`P2`).
+pub fn pnm(bytes: &[u8]) -> anyhow::Result<Image> {
+    let mut pos = 0;
+    let magic = header_token(bytes, &mut pos).context("missing PNM magic number")?;
+    let (color, binary) = match magic.as_str() {
+        "P3" => (true, false),
+        "P6" => (true, true),
+        "P2" => (false, false),
+        "P5" => (false, true),
+        _ => bail!("unsupported PNM type {}", magic),
+    };
+    let mut header = [0usize; 3];
+    for value in header.iter_mut() {
+        *value = header_token(bytes, &mut pos)
+            .context("truncated PNM header")?
+            .parse()
+            .context("invalid PNM header")?;
+    }
+    let [width, height, maxval] = header;
+    if maxval == 0 || maxval > 65535 {
+        bail!("invalid PNM maxval {}", maxval);
+    }
+    let channels = if color { 3 } else { 1 };
+    let count = width
+        .checked_mul(height)
+        .and_then(|n| n.checked_mul(channels))
+        .context("PNM dimensions too large")?;
+
+    let samples: Vec<usize> = if binary {
+        // exactly one whitespace byte separates the header from the raster
+        let data = &bytes[(pos + 1).min(bytes.len())..];
+        let wide = maxval > 255;
+        let needed = count.checked_mul(if wide { 2 } else { 1 });
+        if needed.is_none_or(|needed| data.len() < needed) {
+            bail!("truncated PNM raster");
+        }
+        if wide {
+            data.chunks_exact(2)
+                .take(count)
+                .map(|c| (c[0] as usize) << 8 | c[1] as usize)
+                .collect()
+        } else {
+            data[..count].iter().map(|v| *v as usize).collect()
+        }
+    } else {
+        // each sample takes at least one digit and a separator
+        if count > (bytes.len() - pos) / 2 + 1 {
+            bail!("truncated PNM raster");
+        }
+        let mut samples = Vec::with_capacity(count);
+        for _ in 0..count {
+            samples.push(
+                header_token(bytes, &mut pos)
+                    .context("truncated PNM raster")?
+                    .parse()
+                    .context("invalid PNM sample")?,
+            );
+        }
+        samples
+    };
+
+    let scale = |v: usize| (v.min(maxval) * 255 / maxval) as u8;
+    let pixels = samples
+        .chunks_exact(channels)
+        .map(|p| {
+            if color {
+                (scale(p[0]), scale(p[1]), scale(p[2]))
+            } else {
+                let v = scale(p[0]);
+                (v, v, v)
+            }
+        })
+        .collect();
+    Image::from_rgb(width, height, pixels)
+}
+
+/// Next whitespace-separated token, skipping `#` comments; leaves `pos` on the byte after it.
+fn header_token(bytes: &[u8], pos: &mut usize) -> Option<String> {
+    loop {
+        match bytes.get(*pos)? {
+            b'#' => {
+                while bytes.get(*pos).is_some_and(|b| *b != b'\n') {
+                    *pos += 1;
+                }
+            }
+            b if b.is_ascii_whitespace() => *pos += 1,
+            _ => break,
+        }
+    }
+    let start = *pos;
+    while bytes.get(*pos).is_some_and(|b| !b.is_ascii_whitespace()) {
+        *pos += 1;
+    }
+    Some(String::from_utf8_lossy(&bytes[start..*pos]).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode_png(width: u32, height: u32, color: ColorType, data: &[u8]) -> Vec<u8> {
+        let mut bytes = vec![];
+        let mut encoder = png::Encoder::new(&mut bytes, width, height);
+        encoder.set_color(color);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().unwrap();
+        writer.write_image_data(data).unwrap();
+        writer.finish().unwrap();
+        bytes
+    }
+
+    #[test]
+    fn png_rgb() {
+        let bytes = encode_png(2, 1, ColorType::Rgb, &[255, 0, 0, 0, 0, 255]);
+        let image = png(&bytes).unwrap();
+        assert_eq!((image.width(), image.height()), (2, 1));
+        assert_eq!(image.pixel(0, 0), (255, 0, 0));
+        assert_eq!(image.pixel(1, 0), (0, 0, 255));
+    }
+
+    #[test]
+    fn png_alpha_over_black() {
+        let bytes = encode_png(1, 1, ColorType::Rgba, &[200, 100, 50, 0]);
+        assert_eq!(png(&bytes).unwrap().pixel(0, 0), (0, 0, 0));
+        let bytes = encode_png(1, 1, ColorType::GrayscaleAlpha, &[200, 255]);
+        assert_eq!(png(&bytes).unwrap().pixel(0, 0), (200, 200, 200));
+    }
+
+    #[test]
+    fn png_truncated() {
+        let bytes = encode_png(4, 4, ColorType::Rgb, &[7; 48]);
+        assert!(png(&bytes[..bytes.len() - 20]).is_err());
+        assert!(png(PNG_SIGNATURE).is_err());
+    }
+
+    #[test]
+    fn pnm_binary() {
+        let mut bytes = b"P6\n# comment\n2 1\n255\n".to_vec();
+        bytes.extend([255, 0, 0, 0, 128, 0]);
+        let image = pnm(&bytes).unwrap();
+        assert_eq!(image.pixel(0, 0), (255, 0, 0));
+        assert_eq!(image.pixel(1, 0), (0, 128, 0));
+
+        let mut bytes = b"P5 1 1 65535 ".to_vec();
+        bytes.extend([0xff, 0xff]);
+        assert_eq!(pnm(&bytes).unwrap().pixel(0, 0), (255, 255, 255));
+    }
+
+    #[test]
+    fn pnm_plain() {
+        let image = pnm(b"P2\n2 1\n4\n0 4").unwrap();
+        assert_eq!(image.pixel(0, 0), (0, 0, 0));
+        assert_eq!(image.pixel(1, 0), (255, 255, 255));
+        let image = pnm(b"P3 1 1 255 10 20 30\n").unwrap();
+        assert_eq!(image.pixel(0, 0), (10, 20, 30));
+    }
+
+    #[test]
+    fn pnm_invalid_header() {
+        assert!(pnm(b"P7 1 1 255").is_err());
+        assert!(pnm(b"P6 1").is_err());
+        assert!(pnm(b"P6 1 x 255").is_err());
+        assert!(pnm(b"P6 1 1 0 ").is_err());
+        assert!(pnm(b"P6 1 1 70000 ").is_err());
+    }
+
+    #[test]
+    fn pnm_truncated_raster() {
+        assert!(pnm(b"P6 2 2 255 \x01\x02\x03").is_err());
+        assert!(pnm(b"P5 2 1 65535 \x01\x02\x03").is_err());
+        assert!(pnm(b"P2 2 2 255 1 2 3").is_err());
+    }
+
+    #[test]
+    fn pnm_huge_dimensions() {
+        let header = format!("P6 {} {} 255 ", usize::MAX, 2);
+        assert!(pnm(header.as_bytes()).is_err());
+        let header = format!("P3 {} {} 255 1 2 3", 1 << 20, 1 << 20);
+        assert!(pnm(header.as_bytes()).is_err());
+    }
+}
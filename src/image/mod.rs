@@ -0,0 +1,123 @@
+//! Load raster images and convert them into frame content.
+
+mod convert;
+mod decode;
+
+pub use convert::{ConvertOptions, DEFAULT_RAMP};
+
+use anyhow::{bail, Context};
+use std::fs;
+use std::path::Path;
+
+pub type Rgb = (u8, u8, u8);
+
+/// An 8-bit RGB raster image.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    /// Create an image from row-major pixels.
+    pub fn from_rgb(width: usize, height: usize, pixels: Vec<Rgb>) -> anyhow::Result<Self> {
+        if pixels.len() != width * height {
+            bail!(
+                "expected {} pixels for a {}x{} image, got {}",
+                width * height,
+                width,
+                height,
+                pixels.len()
+            );
+        }
+        Ok(Self {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    /// Load a PNG or PPM/PGM image, detected from the file contents.
+    pub fn load(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let bytes = fs::read(path).with_context(|| format!("failed to read {}", path.display()))?;
+        Self::decode(&bytes).with_context(|| format!("failed to decode {}", path.display()))
+    }
+
+    /// Decode PNG or PPM/PGM bytes.
+    pub fn decode(bytes: &[u8]) -> anyhow::Result<Self> {
+        if bytes.starts_with(decode::PNG_SIGNATURE) {
+            decode::png(bytes)
+        } else if bytes.first() == Some(&b'P') {
+            decode::pnm(bytes)
+        } else {
+            bail!("unsupported image format (expected PNG or PPM)")
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn pixel(&self, x: usize, y: usize) -> Rgb {
+        self.pixels[y * self.width + x]
+    }
+
+    /// Largest (columns, rows) within `max_cols` x `max_rows` that shows the image
+    /// undistorted, where one cell is `cell_aspect` times as wide as it is tall.
+    pub fn fit(&self, max_cols: usize, max_rows: usize, cell_aspect: f32) -> (usize, usize) {
+        if self.width == 0 || self.height == 0 {
+            return (0, 0);
+        }
+        let aspect = self.width as f32 / self.height as f32;
+        let cols = (max_rows as f32 * aspect / cell_aspect).round() as usize;
+        if cols <= max_cols {
+            (cols.max(1), max_rows)
+        } else {
+            let rows = (max_cols as f32 * cell_aspect / aspect).round() as usize;
+            (max_cols, rows.max(1))
+        }
+    }
+
+    /// Resample to `width` x `height` by averaging the source pixels under each target pixel.
+    pub fn resize(&self, width: usize, height: usize) -> Image {
+        let mut pixels = Vec::with_capacity(width * height);
+        for ty in 0..height {
+            let y0 = ty * self.height / height;
+            let y1 = ((ty + 1) * self.height / height)
+                .max(y0 + 1)
+                .min(self.height);
+            for tx in 0..width {
+                let x0 = tx * self.width / width;
+                let x1 = ((tx + 1) * self.width / width).max(x0 + 1).min(self.width);
+                let (mut r, mut g, mut b, mut n) = (0u32, 0u32, 0u32, 0u32);
+                for y in y0..y1 {
+                    for x in x0..x1 {
+                        let p = self.pixel(x, y);
+                        r += p.0 as u32;
+                        g += p.1 as u32;
+                        b += p.2 as u32;
+                        n += 1;
+                    }
+                }
+                let n = n.max(1);
+                pixels.push(((r / n) as u8, (g / n) as u8, (b / n) as u8));
+            }
+        }
+        Image {
+            width,
+            height,
+            pixels,
+        }
+    }
+}
+
+/// Perceived brightness in `0.0..=1.0` (Rec. 709 luma).
+pub fn luminance((r, g, b): Rgb) -> f32 {
+    (0.2126 * r as f32 + 0.7152 * g as f32 + 0.0722 * b as f32) / 255.0
+}
@@ -0,0 +1,341 @@
+use anyhow::{bail, Result};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::fmt;
+
+/// Something a key can be bound to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Action {
+    Quit,
+    /// Pause or resume animation.
+    TogglePause,
+    ScrollUp,
+    ScrollDown,
+    PageUp,
+    PageDown,
+    ScrollTop,
+    ScrollBottom,
+    NextPane,
+    PrevPane,
+    ToggleHelp,
+}
+
+impl Action {
+    /// Short description for help screens.
+    pub fn description(&self) -> &'static str {
+        match self {
+            Action::Quit => "quit",
+            Action::TogglePause => "pause/resume",
+            Action::ScrollUp => "scroll up",
+            Action::ScrollDown => "scroll down",
+            Action::PageUp => "page up",
+            Action::PageDown => "page down",
+            Action::ScrollTop => "scroll to top",
+            Action::ScrollBottom => "scroll to bottom",
+            Action::NextPane => "next pane",
+            Action::PrevPane => "previous pane",
+            Action::ToggleHelp => "toggle help",
+        }
+    }
+}
+
+/// A key plus modifiers, e.g. `ctrl+c`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct KeyBinding {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        // shift is already part of the character (`G`) or the code (`BackTab`)
+        let modifiers = match code {
+            KeyCode::Char(_) | KeyCode::BackTab => modifiers - KeyModifiers::SHIFT,
+            _ => modifiers,
+        };
+        Self { code, modifiers }
+    }
+
+    /// Parse a binding like `q`, `G`, `ctrl+c`, `shift+tab`, `pagedown` or `f1`.
+    pub fn parse(spec: &str) -> Result<Self> {
+        let mut modifiers = KeyModifiers::NONE;
+        // split off modifiers, leaving a trailing `+` as the key itself
+        let mut rest = spec;
+        while let Some((modifier, tail)) = rest.split_once('+').filter(|(_, t)| !t.is_empty()) {
+            modifiers |= match modifier.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" | "meta" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                _ => bail!("unknown modifier '{}' in key binding '{}'", modifier, spec),
+            };
+            rest = tail;
+        }
+        let code = match rest.to_ascii_lowercase().as_str() {
+            "esc" | "escape" => KeyCode::Esc,
+            "enter" | "return" => KeyCode::Enter,
+            "tab" if modifiers.contains(KeyModifiers::SHIFT) => KeyCode::BackTab,
+            "tab" => KeyCode::Tab,
+            "backtab" => KeyCode::BackTab,
+            "space" => KeyCode::Char(' '),
+            "backspace" => KeyCode::Backspace,
+            "delete" | "del" => KeyCode::Delete,
+            "insert" | "ins" => KeyCode::Insert,
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "pageup" | "pgup" => KeyCode::PageUp,
+            "pagedown" | "pgdn" => KeyCode::PageDown,
+            f if f.len() > 1 && f.starts_with('f') && f[1..].parse::<u8>().is_ok() => {
+                KeyCode::F(f[1..].parse()?)
+            }
+            _ => {
+                let mut chars = rest.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) if modifiers.contains(KeyModifiers::SHIFT) => {
+                        KeyCode::Char(c.to_ascii_uppercase())
+                    }
+                    (Some(c), None) => KeyCode::Char(c),
+                    _ => bail!("unknown key '{}' in key binding '{}'", rest, spec),
+                }
+            }
+        };
+        Ok(Self::new(code, modifiers))
+    }
+}
+
+impl From<KeyEvent> for KeyBinding {
+    fn from(event: KeyEvent) -> Self {
+        Self::new(event.code, event.modifiers)
+    }
+}
+
+impl fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "ctrl+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "alt+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            write!(f, "shift+")?;
+        }
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "space"),
+            KeyCode::Char(c) => write!(f, "{}", c),
+            KeyCode::F(n) => write!(f, "f{}", n),
+            KeyCode::BackTab => write!(f, "shift+tab"),
+            KeyCode::PageUp => write!(f, "pageup"),
+            KeyCode::PageDown => write!(f, "pagedown"),
+            code => write!(f, "{}", format!("{:?}", code).to_lowercase()),
+        }
+    }
+}
+
+/// Maps keys to actions. Several keys may share an action.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Keymap {
+    bindings: Vec<(KeyBinding, Action)>,
+}
+
+impl Default for Keymap {
+    /// `q`/`esc`/`ctrl+c` quit, `space` pauses, arrows and vi keys scroll,
+    /// `tab`/`shift+tab` switch panes and `?` toggles help.
+    fn default() -> Self {
+        let defaults = [
+            ("q", Action::Quit),
+            ("esc", Action::Quit),
+            ("ctrl+c", Action::Quit),
+            ("space", Action::TogglePause),
+            ("p", Action::TogglePause),
+            ("up", Action::ScrollUp),
+            ("k", Action::ScrollUp),
+            ("down", Action::ScrollDown),
+            ("j", Action::ScrollDown),
+            ("pageup", Action::PageUp),
+            ("ctrl+u", Action::PageUp),
+            ("pagedown", Action::PageDown),
+            ("ctrl+d", Action::PageDown),
+            ("home", Action::ScrollTop),
+            ("g", Action::ScrollTop),
+            ("end", Action::ScrollBottom),
+            ("G", Action::ScrollBottom),
+            ("tab", Action::NextPane),
+            ("shift+tab", Action::PrevPane),
+            ("?", Action::ToggleHelp),
+            ("f1", Action::ToggleHelp),
+        ];
+        Self {
+            bindings: defaults
+                .iter()
+                .map(|(key, action)| (KeyBinding::parse(key).expect("valid default"), *action))
+                .collect(),
+        }
+    }
+}
+
+impl Keymap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A keymap with nothing bound.
+    pub fn empty() -> Self {
+        Self { bindings: vec![] }
+    }
+
+    /// Bind `key` to `action`, replacing any existing binding for `key`.
+    pub fn bind(&mut self, key: KeyBinding, action: Action) {
+        self.unbind(key);
+        self.bindings.push((key, action));
+    }
+
+    pub fn unbind(&mut self, key: KeyBinding) {
+        self.bindings.retain(|(k, _)| *k != key);
+    }
+
+    /// Bind a key given as a string, see `KeyBinding::parse`.
+    pub fn with_binding(mut self, key: &str, action: Action) -> Result<Self> {
+        self.bind(KeyBinding::parse(key)?, action);
+        Ok(self)
+    }
+
+    /// The action bound to `key`, if any.
+    pub fn action(&self, key: KeyBinding) -> Option<Action> {
+        self.bindings
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, action)| *action)
+    }
+
+    /// Keys bound to each action, in the order actions were first bound.
+    pub fn help(&self) -> Vec<(Action, Vec<KeyBinding>)> {
+        let mut help: Vec<(Action, Vec<KeyBinding>)> = vec![];
+        for (key, action) in &self.bindings {
+            match help.iter_mut().find(|(a, _)| a == action) {
+                Some((_, keys)) => keys.push(*key),
+                None => help.push((*action, vec![*key])),
+            }
+        }
+        help
+    }
+
+    /// Help as one line per action, e.g. `q, esc, ctrl+c  quit`.
+    pub fn help_lines(&self) -> Vec<String> {
+        let help: Vec<(String, &str)> = self
+            .help()
+            .iter()
+            .map(|(action, keys)| {
+                let keys = keys.iter().map(|k| k.to_string()).collect::<Vec<_>>();
+                (keys.join(", "), action.description())
+            })
+            .collect();
+        let width = help
+            .iter()
+            .map(|(k, _)| k.chars().count())
+            .max()
+            .unwrap_or(0);
+        help.iter()
+            .map(|(keys, desc)| format!("{:<width$}  {}", keys, desc, width = width))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyBinding {
+        KeyBinding { code, modifiers }
+    }
+
+    #[test]
+    fn parse_plain_keys() {
+        let none = KeyModifiers::NONE;
+        assert_eq!(
+            KeyBinding::parse("q").unwrap(),
+            key(KeyCode::Char('q'), none)
+        );
+        assert_eq!(
+            KeyBinding::parse("G").unwrap(),
+            key(KeyCode::Char('G'), none)
+        );
+        assert_eq!(
+            KeyBinding::parse("space").unwrap(),
+            key(KeyCode::Char(' '), none)
+        );
+        assert_eq!(
+            KeyBinding::parse("PgDn").unwrap(),
+            key(KeyCode::PageDown, none)
+        );
+        assert_eq!(KeyBinding::parse("esc").unwrap(), key(KeyCode::Esc, none));
+        assert_eq!(KeyBinding::parse("f12").unwrap(), key(KeyCode::F(12), none));
+        assert_eq!(
+            KeyBinding::parse("f").unwrap(),
+            key(KeyCode::Char('f'), none)
+        );
+    }
+
+    #[test]
+    fn parse_modifiers() {
+        assert_eq!(
+            KeyBinding::parse("ctrl+c").unwrap(),
+            key(KeyCode::Char('c'), KeyModifiers::CONTROL)
+        );
+        assert_eq!(
+            KeyBinding::parse("Control+Alt+Delete").unwrap(),
+            key(KeyCode::Delete, KeyModifiers::CONTROL | KeyModifiers::ALT)
+        );
+        assert_eq!(
+            KeyBinding::parse("ctrl++").unwrap(),
+            key(KeyCode::Char('+'), KeyModifiers::CONTROL)
+        );
+        assert_eq!(
+            KeyBinding::parse("+").unwrap(),
+            key(KeyCode::Char('+'), KeyModifiers::NONE)
+        );
+    }
+
+    #[test]
+    fn parse_shift_folds_into_key() {
+        assert_eq!(
+            KeyBinding::parse("shift+g").unwrap(),
+            KeyBinding::parse("G").unwrap()
+        );
+        assert_eq!(
+            KeyBinding::parse("shift+tab").unwrap(),
+            key(KeyCode::BackTab, KeyModifiers::NONE)
+        );
+        assert_eq!(
+            KeyBinding::parse("shift+up").unwrap(),
+            key(KeyCode::Up, KeyModifiers::SHIFT)
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(KeyBinding::parse("").is_err());
+        assert!(KeyBinding::parse("hyper+x").is_err());
+        assert!(KeyBinding::parse("ctrl+nope").is_err());
+        assert!(KeyBinding::parse("f300").is_err());
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for spec in [
+            "q",
+            "ctrl+c",
+            "alt+x",
+            "shift+tab",
+            "pagedown",
+            "f1",
+            "space",
+            "esc",
+        ] {
+            let binding = KeyBinding::parse(spec).unwrap();
+            assert_eq!(KeyBinding::parse(&binding.to_string()).unwrap(), binding);
+        }
+    }
+}
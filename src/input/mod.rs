@@ -0,0 +1,96 @@
+//! Keyboard and mouse input: crossterm events translated into typed events, with keys
+//! mapped through a `Keymap`.
+
+mod keymap;
+mod mouse;
+
+pub use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton};
+pub use keymap::{Action, KeyBinding, Keymap};
+pub use mouse::{MouseAction, MouseCapture, MouseInput};
+
+use anyhow::Result;
+use crossterm::event::{self, Event, KeyEventKind};
+use crossterm::terminal;
+use std::time::Duration;
+
+/// An input event for an application.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputEvent {
+    /// A key bound to an action was pressed.
+    Action(Action),
+    /// A key with no binding was pressed.
+    Key(KeyEvent),
+    /// A mouse event; needs a `MouseCapture`.
+    Mouse(MouseInput),
+    /// The terminal was resized to (columns, rows).
+    Resize(usize, usize),
+}
+
+/// Reads terminal events and maps key presses through a keymap.
+#[derive(Clone, Debug, Default)]
+pub struct Input {
+    pub keymap: Keymap,
+}
+
+impl Input {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_keymap(mut self, keymap: Keymap) -> Self {
+        self.keymap = keymap;
+        self
+    }
+
+    /// Wait up to `timeout` for the next event; `None` if nothing relevant arrived.
+    pub fn poll(&self, timeout: Duration) -> Result<Option<InputEvent>> {
+        if event::poll(timeout)? {
+            Ok(self.translate(event::read()?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Block until the next relevant event.
+    pub fn read(&self) -> Result<InputEvent> {
+        loop {
+            if let Some(event) = self.translate(event::read()?) {
+                return Ok(event);
+            }
+        }
+    }
+
+    /// Map a raw crossterm event; key releases and unhandled event kinds give `None`.
+    pub fn translate(&self, event: Event) -> Option<InputEvent> {
+        match event {
+            Event::Key(key) if key.kind != KeyEventKind::Release => {
+                Some(match self.keymap.action(KeyBinding::from(key)) {
+                    Some(action) => InputEvent::Action(action),
+                    None => InputEvent::Key(key),
+                })
+            }
+            Event::Mouse(mouse) => MouseInput::from_event(mouse).map(InputEvent::Mouse),
+            Event::Resize(cols, rows) => Some(InputEvent::Resize(cols as usize, rows as usize)),
+            _ => None,
+        }
+    }
+}
+
+/// Puts the terminal in raw mode so keys arrive immediately; restored on drop.
+///
+/// Raw mode also stops `ctrl+c` from raising SIGINT, so make sure it's bound to
+/// `Action::Quit` (it is in the default keymap).
+pub struct RawMode(());
+
+impl RawMode {
+    pub fn enable() -> Result<Self> {
+        terminal::enable_raw_mode()?;
+        Ok(Self(()))
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let _ = terminal::disable_raw_mode();
+    }
+}
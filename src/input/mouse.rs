@@ -0,0 +1,82 @@
+use anyhow::Result;
+use crossterm::event::{
+    DisableMouseCapture, EnableMouseCapture, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+use crossterm::execute;
+use std::io::Write;
+
+/// What the mouse did.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MouseAction {
+    Press(MouseButton),
+    Release(MouseButton),
+    Drag(MouseButton),
+    Move,
+    ScrollUp,
+    ScrollDown,
+}
+
+/// A mouse event at a terminal cell (0-based).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MouseInput {
+    pub action: MouseAction,
+    pub column: usize,
+    pub row: usize,
+    pub modifiers: KeyModifiers,
+}
+
+impl MouseInput {
+    /// Map a crossterm mouse event; horizontal scrolling gives `None`.
+    pub fn from_event(event: MouseEvent) -> Option<Self> {
+        let action = match event.kind {
+            MouseEventKind::Down(button) => MouseAction::Press(button),
+            MouseEventKind::Up(button) => MouseAction::Release(button),
+            MouseEventKind::Drag(button) => MouseAction::Drag(button),
+            MouseEventKind::Moved => MouseAction::Move,
+            MouseEventKind::ScrollUp => MouseAction::ScrollUp,
+            MouseEventKind::ScrollDown => MouseAction::ScrollDown,
+            MouseEventKind::ScrollLeft | MouseEventKind::ScrollRight => return None,
+        };
+        Some(Self {
+            action,
+            column: event.column as usize,
+            row: event.row as usize,
+            modifiers: event.modifiers,
+        })
+    }
+
+    /// Lines to scroll for a wheel event: negative is up, zero for anything else.
+    pub fn scroll_delta(&self) -> isize {
+        match self.action {
+            MouseAction::ScrollUp => -WHEEL_LINES,
+            MouseAction::ScrollDown => WHEEL_LINES,
+            _ => 0,
+        }
+    }
+}
+
+/// Lines scrolled per wheel notch.
+const WHEEL_LINES: isize = 3;
+
+/// Asks the terminal to report mouse events; restored on drop.
+pub struct MouseCapture {
+    out: Box<dyn Write>,
+}
+
+impl MouseCapture {
+    pub fn enable() -> Result<Self> {
+        Self::enable_on(std::io::stdout())
+    }
+
+    /// Enable capture on a terminal other than stdout.
+    pub fn enable_on<W: Write + 'static>(mut out: W) -> Result<Self> {
+        execute!(out, EnableMouseCapture)?;
+        Ok(Self { out: Box::new(out) })
+    }
+}
+
+impl Drop for MouseCapture {
+    fn drop(&mut self) {
+        let _ = execute!(self.out, DisableMouseCapture);
+    }
+}
@@ -0,0 +1,14 @@
+pub mod animations;
+pub mod app;
+pub mod export;
+pub mod follow;
+pub mod frame;
+pub mod geo;
+pub mod highlight;
+pub mod image;
+pub mod input;
+#[cfg(feature = "async")]
+pub mod stream;
+pub mod terminal;
+pub mod watch;
+pub mod widgets;
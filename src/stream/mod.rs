@@ -0,0 +1,119 @@
+//! Async integration for tokio applications, behind the `async` feature.
+//!
+//! Frames are drawn by a `RenderThread`, whose `RenderSender` is a `Sink` of content,
+//! so nothing here blocks the runtime.
+
+use crate::animations::Animation;
+use crate::frame::text::Text;
+use crate::frame::RenderSender;
+use futures_core::Stream;
+use futures_sink::Sink;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+use std::time::Duration;
+use tokio::time::{Instant, Interval, MissedTickBehavior};
+
+pub use crossterm::event::{Event, EventStream};
+
+impl Sink<Text> for RenderSender {
+    type Error = anyhow::Error;
+
+    /// The render thread's queue is unbounded, so it's always ready.
+    fn poll_ready(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<anyhow::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: Text) -> anyhow::Result<()> {
+        self.update_text(item)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<anyhow::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<anyhow::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// One frame yielded by an `AnimationStream`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AnimationFrame {
+    /// Index into the animation's frames.
+    pub index: usize,
+    /// Frame slots since the stream started; gaps mean frames were skipped.
+    pub slot: u64,
+    /// When the frame was due.
+    pub due: Instant,
+    pub content: String,
+}
+
+/// Yields animation frames on a timer.
+///
+/// Frames are picked by elapsed time, so if the consumer falls behind the late frames
+/// are skipped instead of bunching up. Must be created inside a tokio runtime.
+pub struct AnimationStream {
+    frames: Vec<String>,
+    delay: Duration,
+    interval: Interval,
+    start: Instant,
+    loops: Option<u64>,
+}
+
+impl AnimationStream {
+    /// Stream `frames`, showing each for `delay`, forever.
+    pub fn new(frames: Vec<String>, delay: Duration) -> Self {
+        let delay = delay.max(Duration::from_millis(1));
+        let start = Instant::now();
+        let mut interval = tokio::time::interval_at(start, delay);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        Self {
+            frames,
+            delay,
+            interval,
+            start,
+            loops: None,
+        }
+    }
+
+    /// End the stream after playing the animation `loops` times.
+    pub fn with_loops(mut self, loops: u64) -> Self {
+        self.loops = Some(loops);
+        self
+    }
+}
+
+impl Stream for AnimationStream {
+    type Item = AnimationFrame;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<AnimationFrame>> {
+        if self.frames.is_empty() {
+            return Poll::Ready(None);
+        }
+        let due = ready!(self.interval.poll_tick(cx));
+        let slot = ((due - self.start).as_nanos() / self.delay.as_nanos()) as u64;
+        let len = self.frames.len() as u64;
+        if self.loops.is_some_and(|loops| slot >= loops * len) {
+            return Poll::Ready(None);
+        }
+        let index = (slot % len) as usize;
+        Poll::Ready(Some(AnimationFrame {
+            index,
+            slot,
+            due,
+            content: self.frames[index].clone(),
+        }))
+    }
+}
+
+impl Animation {
+    /// Stream this animation's frames, showing each for `delay`.
+    pub fn stream(&self, delay: Duration) -> AnimationStream {
+        AnimationStream::new(self.frames().iter().map(|f| f.to_string()).collect(), delay)
+    }
+}
+
+/// Terminal input and resize events as a `Stream`.
+pub fn events() -> EventStream {
+    EventStream::new()
+}
@@ -0,0 +1,127 @@
+use crate::input::{KeyCode, KeyEvent, KeyModifiers};
+
+/// The bytes a terminal sends for `key`, or `None` for keys it has no encoding for.
+///
+/// `application_cursor` selects the `ESC O` arrow keys some full-screen programs ask for.
+pub fn key_bytes(key: KeyEvent, application_cursor: bool) -> Option<Vec<u8>> {
+    let mods = key.modifiers;
+    // xterm's modifier parameter: 1 + shift + 2 * alt + 4 * ctrl
+    let param = 1
+        + mods.contains(KeyModifiers::SHIFT) as u8
+        + 2 * mods.contains(KeyModifiers::ALT) as u8
+        + 4 * mods.contains(KeyModifiers::CONTROL) as u8;
+    let cursor = |ch: char| match (param, application_cursor) {
+        (1, true) => format!("\x1bO{}", ch),
+        (1, false) => format!("\x1b[{}", ch),
+        _ => format!("\x1b[1;{}{}", param, ch),
+    };
+    let tilde = |n: u8| match param {
+        1 => format!("\x1b[{}~", n),
+        _ => format!("\x1b[{};{}~", n, param),
+    };
+    let seq = match key.code {
+        KeyCode::Char(ch) => {
+            let mut bytes = vec![];
+            if mods.contains(KeyModifiers::ALT) {
+                bytes.push(0x1b);
+            }
+            match control_byte(ch).filter(|_| mods.contains(KeyModifiers::CONTROL)) {
+                Some(byte) => bytes.push(byte),
+                None => bytes.extend_from_slice(ch.to_string().as_bytes()),
+            }
+            return Some(bytes);
+        }
+        KeyCode::Enter => "\r".to_owned(),
+        KeyCode::Tab => "\t".to_owned(),
+        KeyCode::BackTab => "\x1b[Z".to_owned(),
+        KeyCode::Backspace => "\x7f".to_owned(),
+        KeyCode::Esc => "\x1b".to_owned(),
+        KeyCode::Up => cursor('A'),
+        KeyCode::Down => cursor('B'),
+        KeyCode::Right => cursor('C'),
+        KeyCode::Left => cursor('D'),
+        KeyCode::Home => cursor('H'),
+        KeyCode::End => cursor('F'),
+        KeyCode::Insert => tilde(2),
+        KeyCode::Delete => tilde(3),
+        KeyCode::PageUp => tilde(5),
+        KeyCode::PageDown => tilde(6),
+        KeyCode::F(n @ 1..=4) if param == 1 => format!("\x1bO{}", (b'P' + n - 1) as char),
+        KeyCode::F(n @ 1..=4) => format!("\x1b[1;{}{}", param, (b'P' + n - 1) as char),
+        KeyCode::F(n @ 5..=12) => tilde([15, 17, 18, 19, 20, 21, 23, 24][n as usize - 5]),
+        _ => return None,
+    };
+    Some(seq.into_bytes())
+}
+
+/// The control character typed as ctrl + `ch`, e.g. `0x03` for ctrl+c.
+fn control_byte(ch: char) -> Option<u8> {
+    match ch {
+        'a'..='z' | 'A'..='Z' => Some(ch.to_ascii_lowercase() as u8 - b'a' + 1),
+        ' ' | '@' | '2' => Some(0),
+        '[' | '3' => Some(0x1b),
+        '\\' | '4' => Some(0x1c),
+        ']' | '5' => Some(0x1d),
+        '^' | '6' => Some(0x1e),
+        '_' | '-' | '7' => Some(0x1f),
+        '?' | '8' => Some(0x7f),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bytes(code: KeyCode, modifiers: KeyModifiers) -> Vec<u8> {
+        key_bytes(KeyEvent::new(code, modifiers), false).unwrap()
+    }
+
+    #[test]
+    fn characters() {
+        assert_eq!(bytes(KeyCode::Char('a'), KeyModifiers::NONE), b"a");
+        assert_eq!(
+            bytes(KeyCode::Char('é'), KeyModifiers::NONE),
+            "é".as_bytes()
+        );
+        assert_eq!(bytes(KeyCode::Char('c'), KeyModifiers::CONTROL), [0x03]);
+        assert_eq!(bytes(KeyCode::Char('['), KeyModifiers::CONTROL), [0x1b]);
+        assert_eq!(bytes(KeyCode::Char('x'), KeyModifiers::ALT), b"\x1bx");
+        assert_eq!(
+            bytes(
+                KeyCode::Char('a'),
+                KeyModifiers::CONTROL | KeyModifiers::ALT
+            ),
+            [0x1b, 0x01]
+        );
+    }
+
+    #[test]
+    fn cursor_keys() {
+        assert_eq!(bytes(KeyCode::Up, KeyModifiers::NONE), b"\x1b[A");
+        let up = KeyEvent::new(KeyCode::Up, KeyModifiers::NONE);
+        assert_eq!(key_bytes(up, true).unwrap(), b"\x1bOA");
+        assert_eq!(bytes(KeyCode::Left, KeyModifiers::CONTROL), b"\x1b[1;5D");
+        assert_eq!(bytes(KeyCode::End, KeyModifiers::SHIFT), b"\x1b[1;2F");
+    }
+
+    #[test]
+    fn editing_and_function_keys() {
+        assert_eq!(bytes(KeyCode::Enter, KeyModifiers::NONE), b"\r");
+        assert_eq!(bytes(KeyCode::Backspace, KeyModifiers::NONE), b"\x7f");
+        assert_eq!(bytes(KeyCode::BackTab, KeyModifiers::SHIFT), b"\x1b[Z");
+        assert_eq!(bytes(KeyCode::Delete, KeyModifiers::NONE), b"\x1b[3~");
+        assert_eq!(bytes(KeyCode::PageDown, KeyModifiers::ALT), b"\x1b[6;3~");
+        assert_eq!(bytes(KeyCode::F(1), KeyModifiers::NONE), b"\x1bOP");
+        assert_eq!(bytes(KeyCode::F(2), KeyModifiers::SHIFT), b"\x1b[1;2Q");
+        assert_eq!(bytes(KeyCode::F(12), KeyModifiers::NONE), b"\x1b[24~");
+    }
+
+    #[test]
+    fn unsupported_keys() {
+        let key = KeyEvent::new(KeyCode::F(20), KeyModifiers::NONE);
+        assert_eq!(key_bytes(key, false), None);
+        let key = KeyEvent::new(KeyCode::CapsLock, KeyModifiers::NONE);
+        assert_eq!(key_bytes(key, false), None);
+    }
+}
@@ -0,0 +1,17 @@
+//! A virtual terminal: a VT100/xterm escape-sequence parser driving a screen of styled
+//! cells, an interpreter for captured command output, and with the `pty` feature, a
+//! pane that runs a program in a pseudo-terminal.
+
+mod keys;
+#[cfg(feature = "pty")]
+mod pane;
+mod parser;
+mod screen;
+mod transcript;
+
+pub use keys::key_bytes;
+#[cfg(feature = "pty")]
+pub use pane::{CommandBuilder, ExitStatus, TerminalPane};
+pub use parser::{Parser, Perform};
+pub use screen::Screen;
+pub use transcript::{interpret, Transcript};
@@ -0,0 +1,175 @@
+use super::keys::key_bytes;
+use super::screen::Screen;
+use crate::frame::style::Style;
+use crate::frame::text::Text;
+use crate::input::KeyEvent;
+use anyhow::{anyhow, Context, Result};
+use portable_pty::{native_pty_system, Child, MasterPty, PtySize};
+use std::io::{Read, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+pub use portable_pty::{CommandBuilder, ExitStatus};
+
+/// A program running in a pseudo-terminal, with its screen kept up to date on a
+/// background thread. Size it to the frame's content area and show `text()` as content.
+pub struct TerminalPane {
+    screen: Arc<Mutex<Screen>>,
+    master: Box<dyn MasterPty + Send>,
+    writer: Arc<Mutex<Box<dyn Write + Send>>>,
+    child: Box<dyn Child + Send + Sync>,
+    status: Option<ExitStatus>,
+    closed: Arc<AtomicBool>,
+}
+
+impl TerminalPane {
+    /// Run `command` through the shell in a terminal of `width` × `height` cells.
+    pub fn spawn(command: &str, width: usize, height: usize) -> Result<Self> {
+        let mut cmd = if cfg!(windows) {
+            CommandBuilder::new("cmd")
+        } else {
+            CommandBuilder::new("sh")
+        };
+        cmd.args([if cfg!(windows) { "/C" } else { "-c" }, command]);
+        Self::spawn_command(cmd, width, height).with_context(|| format!("cannot run '{}'", command))
+    }
+
+    /// Run the user's shell in a terminal of `width` × `height` cells.
+    pub fn shell(width: usize, height: usize) -> Result<Self> {
+        Self::spawn_command(CommandBuilder::new_default_prog(), width, height)
+            .context("cannot start shell")
+    }
+
+    /// Run `cmd` in a terminal of `width` × `height` cells. It starts in the current
+    /// directory unless `cmd` says otherwise.
+    pub fn spawn_command(mut cmd: CommandBuilder, width: usize, height: usize) -> Result<Self> {
+        let screen = Screen::new(width, height);
+        let pair = native_pty_system().openpty(pty_size(screen.width(), screen.height()))?;
+        if cmd.get_cwd().is_none() {
+            if let Ok(dir) = std::env::current_dir() {
+                cmd.cwd(dir);
+            }
+        }
+        cmd.env("TERM", "xterm-256color");
+        let child = pair.slave.spawn_command(cmd)?;
+        // the child holds its own handle; ours would keep the pty open after it exits
+        drop(pair.slave);
+        let mut reader = pair.master.try_clone_reader()?;
+        let writer = Arc::new(Mutex::new(pair.master.take_writer()?));
+        let screen = Arc::new(Mutex::new(screen));
+        let closed = Arc::new(AtomicBool::new(false));
+        {
+            let (screen, writer, closed) = (screen.clone(), writer.clone(), closed.clone());
+            std::thread::spawn(move || {
+                let mut buf = [0; 4096];
+                while let Ok(n @ 1..) = reader.read(&mut buf) {
+                    let responses = {
+                        let mut screen = lock(&screen);
+                        screen.process(&buf[..n]);
+                        screen.take_responses()
+                    };
+                    if !responses.is_empty() {
+                        let mut writer = lock(&writer);
+                        let _ = writer.write_all(&responses).and_then(|_| writer.flush());
+                    }
+                }
+                closed.store(true, Ordering::Relaxed);
+            });
+        }
+        Ok(Self {
+            screen,
+            master: pair.master,
+            writer,
+            child,
+            status: None,
+            closed,
+        })
+    }
+
+    /// The terminal screen; hold the guard briefly, output is processed while it's free.
+    pub fn screen(&self) -> MutexGuard<'_, Screen> {
+        lock(&self.screen)
+    }
+
+    /// The screen as frame content, with the cursor in reverse video.
+    pub fn text(&self) -> Text {
+        self.screen()
+            .text_with_cursor(Style::new().with_reverse(true))
+    }
+
+    /// Size of the terminal as (columns, rows).
+    pub fn size(&self) -> (usize, usize) {
+        let screen = self.screen();
+        (screen.width(), screen.height())
+    }
+
+    /// Resize the terminal; the program is told through `SIGWINCH`.
+    pub fn resize(&self, width: usize, height: usize) -> Result<()> {
+        let mut screen = self.screen();
+        if (screen.width(), screen.height()) == (width.max(1), height.max(1)) {
+            return Ok(());
+        }
+        screen.resize(width, height);
+        self.master
+            .resize(pty_size(screen.width(), screen.height()))
+            .context("cannot resize terminal")
+    }
+
+    /// Send raw bytes to the program, as if typed.
+    pub fn write(&self, bytes: &[u8]) -> Result<()> {
+        let mut writer = lock(&self.writer);
+        writer.write_all(bytes)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Send a key press to the program. Keys with no terminal encoding are ignored.
+    pub fn send_key(&self, key: KeyEvent) -> Result<()> {
+        let application_cursor = self.screen().application_cursor();
+        match key_bytes(key, application_cursor) {
+            Some(bytes) => self.write(&bytes),
+            None => Ok(()),
+        }
+    }
+
+    /// The program's exit status, once it has exited and its output has been read.
+    pub fn try_wait(&mut self) -> Result<Option<ExitStatus>> {
+        if self.status.is_none() {
+            self.status = self.child.try_wait()?;
+        }
+        if self.closed.load(Ordering::Relaxed) || cfg!(windows) {
+            Ok(self.status.clone())
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Kill the program.
+    pub fn kill(&mut self) -> Result<()> {
+        self.child
+            .kill()
+            .map_err(|err| anyhow!("cannot kill program: {}", err))
+    }
+}
+
+impl Drop for TerminalPane {
+    fn drop(&mut self) {
+        if matches!(self.child.try_wait(), Ok(None)) {
+            let _ = self.child.kill();
+        }
+    }
+}
+
+fn pty_size(width: usize, height: usize) -> PtySize {
+    PtySize {
+        rows: height.min(u16::MAX as usize) as u16,
+        cols: width.min(u16::MAX as usize) as u16,
+        pixel_width: 0,
+        pixel_height: 0,
+    }
+}
+
+/// Lock `mutex`, carrying on if a panicking thread poisoned it.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|err| err.into_inner())
+}
@@ -0,0 +1,328 @@
+/// Receives what a `Parser` recognises in a byte stream.
+pub trait Perform {
+    /// A printable character.
+    fn print(&mut self, ch: char);
+
+    /// A C0 control such as `\r`, `\n` or backspace.
+    fn execute(&mut self, byte: u8);
+
+    /// A control sequence `CSI params intermediates action`. Private markers like the `?`
+    /// in `CSI ? 25 h` are passed as intermediates; empty parameters are 0.
+    fn csi(&mut self, _params: &[u16], _intermediates: &[u8], _action: char) {}
+
+    /// An escape sequence `ESC intermediates action`.
+    fn esc(&mut self, _intermediates: &[u8], _action: char) {}
+
+    /// An operating system command `OSC data BEL`, e.g. `0;title` to set the title.
+    fn osc(&mut self, _data: &[u8]) {}
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum State {
+    #[default]
+    Ground,
+    Escape,
+    EscapeIntermediate,
+    Csi,
+    CsiIgnore,
+    Osc,
+    /// Device control, privacy message and application program strings are skipped.
+    String,
+    /// Saw `ESC` inside an OSC or other string; `\` ends it.
+    StringEscape,
+}
+
+/// Most parameters kept for one control sequence; the rest are dropped.
+const MAX_PARAMS: usize = 32;
+
+/// Most bytes kept of an OSC string; the rest are dropped.
+const MAX_OSC: usize = 4096;
+
+/// Incremental parser for VT100/xterm output: UTF-8 text interleaved with control
+/// characters and escape sequences. Input can be split anywhere, even inside a
+/// multi-byte character or an escape sequence.
+#[derive(Clone, Debug, Default)]
+pub struct Parser {
+    state: State,
+    /// Bytes of an incomplete UTF-8 character.
+    utf8: Vec<u8>,
+    params: Vec<u16>,
+    param: u16,
+    intermediates: Vec<u8>,
+    osc: Vec<u8>,
+    /// Whether the string being ended by `ESC \` is an OSC.
+    in_osc: bool,
+}
+
+impl Parser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed `bytes` through the parser, calling `performer` for everything recognised.
+    pub fn advance(&mut self, bytes: &[u8], performer: &mut impl Perform) {
+        for &byte in bytes {
+            if self.utf8.is_empty() && byte < 0x80 {
+                self.char(byte as char, performer);
+            } else {
+                self.utf8_byte(byte, performer);
+            }
+        }
+    }
+
+    fn utf8_byte(&mut self, byte: u8, performer: &mut impl Perform) {
+        if !self.utf8.is_empty() && byte & 0xC0 != 0x80 {
+            // the pending character was cut short; the byte starts something new
+            self.utf8.clear();
+            self.char(char::REPLACEMENT_CHARACTER, performer);
+            return self.advance(&[byte], performer);
+        }
+        self.utf8.push(byte);
+        let expected = match self.utf8[0] {
+            0xC0..=0xDF => 2,
+            0xE0..=0xEF => 3,
+            0xF0..=0xF7 => 4,
+            _ => 1,
+        };
+        if self.utf8.len() >= expected {
+            let ch = std::str::from_utf8(&self.utf8)
+                .ok()
+                .and_then(|s| s.chars().next())
+                .unwrap_or(char::REPLACEMENT_CHARACTER);
+            self.utf8.clear();
+            self.char(ch, performer);
+        }
+    }
+
+    fn char(&mut self, ch: char, performer: &mut impl Perform) {
+        match ch {
+            // cancel any sequence in progress
+            '\x18' | '\x1a' => {
+                self.state = State::Ground;
+                return;
+            }
+            '\x1b' => {
+                match self.state {
+                    State::Osc | State::String => {
+                        self.in_osc = self.state == State::Osc;
+                        self.state = State::StringEscape;
+                    }
+                    _ => self.enter(State::Escape),
+                }
+                return;
+            }
+            _ => {}
+        }
+        let control = (ch as u32) < 0x20;
+        match self.state {
+            State::Ground => {
+                if control {
+                    performer.execute(ch as u8);
+                } else if ch != '\x7f' && !('\u{80}'..'\u{a0}').contains(&ch) {
+                    performer.print(ch);
+                }
+            }
+            State::Escape | State::EscapeIntermediate if control => performer.execute(ch as u8),
+            State::Escape if ch == '[' => self.enter(State::Csi),
+            State::Escape if ch == ']' => self.enter(State::Osc),
+            State::Escape if matches!(ch, 'P' | 'X' | '^' | '_') => self.enter(State::String),
+            State::Escape | State::EscapeIntermediate => match ch {
+                ' '..='/' => {
+                    self.intermediates.push(ch as u8);
+                    self.state = State::EscapeIntermediate;
+                }
+                '0'..='~' => {
+                    performer.esc(&self.intermediates, ch);
+                    self.state = State::Ground;
+                }
+                _ => self.state = State::Ground,
+            },
+            State::Csi | State::CsiIgnore if control => performer.execute(ch as u8),
+            State::Csi => match ch {
+                '0'..='9' => {
+                    self.param = self
+                        .param
+                        .saturating_mul(10)
+                        .saturating_add(ch as u16 - '0' as u16);
+                }
+                ';' | ':' => self.push_param(),
+                '<'..='?' if self.params.is_empty() && self.param == 0 => {
+                    self.intermediates.push(ch as u8);
+                }
+                ' '..='/' => self.intermediates.push(ch as u8),
+                '@'..='~' => {
+                    self.push_param();
+                    performer.csi(&self.params, &self.intermediates, ch);
+                    self.state = State::Ground;
+                }
+                '\x7f' => {}
+                _ => self.state = State::CsiIgnore,
+            },
+            State::CsiIgnore => {
+                if ('@'..='~').contains(&ch) {
+                    self.state = State::Ground;
+                }
+            }
+            State::Osc => match ch {
+                '\x07' => {
+                    performer.osc(&self.osc);
+                    self.state = State::Ground;
+                }
+                _ if control => {}
+                _ => {
+                    let mut buf = [0; 4];
+                    let bytes = ch.encode_utf8(&mut buf).as_bytes();
+                    if self.osc.len() + bytes.len() <= MAX_OSC {
+                        self.osc.extend_from_slice(bytes);
+                    }
+                }
+            },
+            State::String => {
+                if ch == '\x07' {
+                    self.state = State::Ground;
+                }
+            }
+            State::StringEscape => {
+                if self.in_osc {
+                    performer.osc(&self.osc);
+                }
+                if ch == '\\' {
+                    self.state = State::Ground;
+                } else {
+                    // the ESC started a new sequence
+                    self.enter(State::Escape);
+                    self.char(ch, performer);
+                }
+            }
+        }
+    }
+
+    fn enter(&mut self, state: State) {
+        self.state = state;
+        self.params.clear();
+        self.param = 0;
+        self.intermediates.clear();
+        self.osc.clear();
+    }
+
+    fn push_param(&mut self) {
+        if self.params.len() < MAX_PARAMS {
+            self.params.push(self.param);
+        }
+        self.param = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Records everything the parser reports.
+    #[derive(Debug, Default, PartialEq)]
+    struct Log(Vec<String>);
+
+    impl Perform for Log {
+        fn print(&mut self, ch: char) {
+            match self.0.last_mut() {
+                Some(last) if last.starts_with('"') => last.insert(last.len() - 1, ch),
+                _ => self.0.push(format!("\"{}\"", ch)),
+            }
+        }
+
+        fn execute(&mut self, byte: u8) {
+            self.0.push(format!("exec {:#04x}", byte));
+        }
+
+        fn csi(&mut self, params: &[u16], intermediates: &[u8], action: char) {
+            let intermediates = String::from_utf8_lossy(intermediates);
+            self.0
+                .push(format!("csi {}{:?} {}", intermediates, params, action));
+        }
+
+        fn esc(&mut self, intermediates: &[u8], action: char) {
+            let intermediates = String::from_utf8_lossy(intermediates);
+            self.0.push(format!("esc {}{}", intermediates, action));
+        }
+
+        fn osc(&mut self, data: &[u8]) {
+            self.0
+                .push(format!("osc {}", String::from_utf8_lossy(data)));
+        }
+    }
+
+    fn parse(chunks: &[&[u8]]) -> Vec<String> {
+        let (mut parser, mut log) = (Parser::new(), Log::default());
+        for chunk in chunks {
+            parser.advance(chunk, &mut log);
+        }
+        log.0
+    }
+
+    #[test]
+    fn text_and_controls() {
+        assert_eq!(
+            parse(&[b"ab\r\nc\x7f"]),
+            ["\"ab\"", "exec 0x0d", "exec 0x0a", "\"c\""]
+        );
+    }
+
+    #[test]
+    fn csi_params_and_markers() {
+        assert_eq!(
+            parse(&[b"\x1b[1;31m\x1b[?25l\x1b[;5H\x1b[m"]),
+            ["csi [1, 31] m", "csi ?[25] l", "csi [0, 5] H", "csi [0] m"]
+        );
+    }
+
+    #[test]
+    fn esc_sequences() {
+        assert_eq!(parse(&[b"\x1b7\x1b(B\x1bM"]), ["esc 7", "esc (B", "esc M"]);
+    }
+
+    #[test]
+    fn split_anywhere() {
+        let whole = parse(&["x\x1b[12;34Hé\x1b]0;t\x07".as_bytes()]);
+        let bytes = "x\x1b[12;34Hé\x1b]0;t\x07".as_bytes();
+        let split: Vec<&[u8]> = bytes.chunks(1).collect();
+        assert_eq!(parse(&split), whole);
+        assert_eq!(whole, ["\"x\"", "csi [12, 34] H", "\"é\"", "osc 0;t"]);
+    }
+
+    #[test]
+    fn invalid_utf8_is_replaced() {
+        assert_eq!(parse(&[b"\xc3a"]), ["\"\u{fffd}a\""]);
+    }
+
+    #[test]
+    fn osc_terminators() {
+        assert_eq!(parse(&[b"\x1b]2;title\x1b\\z"]), ["osc 2;title", "\"z\""]);
+        // the ESC of an unterminated OSC starts the next sequence
+        assert_eq!(parse(&[b"\x1b]0;a\x1b[A"]), ["osc 0;a", "csi [0] A"]);
+    }
+
+    #[test]
+    fn osc_is_capped() {
+        let mut bytes = b"\x1b]0;".to_vec();
+        bytes.extend(std::iter::repeat_n(b'x', 3 * MAX_OSC));
+        bytes.push(0x07);
+        let log = parse(&[&bytes]);
+        assert_eq!(log.len(), 1);
+        assert_eq!(log[0].len(), "osc ".len() + MAX_OSC);
+    }
+
+    #[test]
+    fn cancel_and_strings_are_dropped() {
+        assert_eq!(parse(&[b"\x1b[12\x18a\x1bPq#0\x1b\\b"]), ["\"ab\""]);
+    }
+
+    #[test]
+    fn too_many_params_are_dropped() {
+        let mut bytes = b"\x1b[".to_vec();
+        for _ in 0..100 {
+            bytes.extend(b"1;");
+        }
+        bytes.push(b'm');
+        let log = parse(&[&bytes]);
+        assert_eq!(log[0].matches('1').count(), MAX_PARAMS);
+    }
+}
@@ -0,0 +1,556 @@
+use super::parser::{Parser, Perform};
+use crate::frame::rendered::Cell;
+use crate::frame::style::Style;
+use crate::frame::text::{Line, Span, Text};
+
+/// Columns between tab stops.
+const TAB_WIDTH: usize = 8;
+
+/// Cursor position and style saved by `ESC 7` / `CSI s`.
+#[derive(Clone, Copy, Debug, Default)]
+struct Saved {
+    x: usize,
+    y: usize,
+    style: Style,
+}
+
+/// The screen of a virtual VT100/xterm terminal: a grid of styled cells and a cursor,
+/// updated by feeding it a program's output.
+#[derive(Clone, Debug)]
+pub struct Screen {
+    width: usize,
+    height: usize,
+    grid: Vec<Vec<Cell>>,
+    /// The main screen while the alternate screen is shown.
+    main: Option<Vec<Vec<Cell>>>,
+    x: usize,
+    y: usize,
+    style: Style,
+    saved: Saved,
+    /// Scrolling region, as a range of rows.
+    top: usize,
+    bottom: usize,
+    /// The last column was written; the next character goes on a new line.
+    wrap_pending: bool,
+    autowrap: bool,
+    cursor_visible: bool,
+    /// Arrow keys should send `ESC O` sequences.
+    application_cursor: bool,
+    title: String,
+    /// Replies to queries like the cursor position report, to be written back to the
+    /// program.
+    responses: Vec<u8>,
+    parser: Parser,
+}
+
+impl Screen {
+    /// A blank screen of `width` columns and `height` rows.
+    pub fn new(width: usize, height: usize) -> Self {
+        let (width, height) = (width.max(1), height.max(1));
+        Self {
+            width,
+            height,
+            grid: vec![vec![blank(Style::default()); width]; height],
+            main: None,
+            x: 0,
+            y: 0,
+            style: Style::default(),
+            saved: Saved::default(),
+            top: 0,
+            bottom: height,
+            wrap_pending: false,
+            autowrap: true,
+            cursor_visible: true,
+            application_cursor: false,
+            title: String::new(),
+            responses: vec![],
+            parser: Parser::new(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Cursor position as (column, row).
+    pub fn cursor(&self) -> (usize, usize) {
+        (self.x, self.y)
+    }
+
+    pub fn cursor_visible(&self) -> bool {
+        self.cursor_visible
+    }
+
+    /// Whether the program asked for application cursor keys; see `key_bytes`.
+    pub fn application_cursor(&self) -> bool {
+        self.application_cursor
+    }
+
+    /// Window title set by the program, if any.
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn rows(&self) -> &[Vec<Cell>] {
+        &self.grid
+    }
+
+    /// Interpret a chunk of program output.
+    pub fn process(&mut self, bytes: &[u8]) {
+        let mut parser = std::mem::take(&mut self.parser);
+        parser.advance(bytes, self);
+        self.parser = parser;
+    }
+
+    /// Replies queued for the program since the last call.
+    pub fn take_responses(&mut self) -> Vec<u8> {
+        std::mem::take(&mut self.responses)
+    }
+
+    /// Change the size of the screen. Rows are dropped from the top when it gets shorter
+    /// so the cursor's line stays visible; the scrolling region is reset.
+    pub fn resize(&mut self, width: usize, height: usize) {
+        let (width, height) = (width.max(1), height.max(1));
+        for grid in std::iter::once(&mut self.grid).chain(self.main.as_mut()) {
+            for row in grid.iter_mut() {
+                row.resize(width, blank(Style::default()));
+            }
+            if grid.len() > height {
+                let excess = grid.len() - height;
+                let from_top = excess.min(self.y + 1 - height.min(self.y + 1));
+                grid.drain(..from_top);
+                grid.truncate(height);
+            }
+            grid.resize(height, vec![blank(Style::default()); width]);
+        }
+        if self.y >= height {
+            self.y = height - 1;
+        }
+        self.width = width;
+        self.height = height;
+        self.x = self.x.min(width - 1);
+        self.top = 0;
+        self.bottom = height;
+        self.wrap_pending = false;
+    }
+
+    /// The screen as frame content, one line per row with trailing blanks removed.
+    pub fn text(&self) -> Text {
+        self.lines(None)
+    }
+
+    /// Like `text`, with the cursor drawn by patching `style` onto its cell when visible.
+    pub fn text_with_cursor(&self, style: Style) -> Text {
+        self.lines(self.cursor_visible.then_some(style))
+    }
+
+    fn lines(&self, cursor: Option<Style>) -> Text {
+        let mut text = Text::default();
+        for (y, row) in self.grid.iter().enumerate() {
+            let cursor_x = cursor.filter(|_| y == self.y).map(|_| self.x);
+            let end = row
+                .iter()
+                .rposition(|c| c.ch != ' ' || !c.style.is_plain())
+                .map(|i| i + 1)
+                .unwrap_or(0)
+                .max(cursor_x.map(|x| x + 1).unwrap_or(0));
+            let mut line = Line::default();
+            for (x, cell) in row[..end].iter().enumerate() {
+                let style = match cursor {
+                    Some(cursor) if cursor_x == Some(x) => cell.style.patch(cursor),
+                    _ => cell.style,
+                };
+                match line.spans.last_mut() {
+                    Some(span) if span.style == style => span.text.push(cell.ch),
+                    _ => line.push(Span::styled(&cell.ch.to_string(), style)),
+                }
+            }
+            text.push(line);
+        }
+        text
+    }
+
+    /// Empty cell in the current background color.
+    fn blank(&self) -> Cell {
+        blank(Style {
+            bg: self.style.bg,
+            ..Style::default()
+        })
+    }
+
+    fn erase(&mut self, y: usize, xs: std::ops::Range<usize>) {
+        let cell = self.blank();
+        let row = &mut self.grid[y];
+        let end = xs.end.min(row.len());
+        for c in &mut row[xs.start.min(end)..end] {
+            *c = cell;
+        }
+    }
+
+    /// Scroll the scrolling region up by `n` lines, adding blank lines at the bottom.
+    fn scroll_up(&mut self, n: usize) {
+        let n = n.min(self.bottom - self.top);
+        let row = vec![self.blank(); self.width];
+        self.grid.drain(self.top..self.top + n);
+        for _ in 0..n {
+            self.grid.insert(self.bottom - n, row.clone());
+        }
+    }
+
+    /// Scroll the scrolling region down by `n` lines, adding blank lines at the top.
+    fn scroll_down(&mut self, n: usize) {
+        let n = n.min(self.bottom - self.top);
+        let row = vec![self.blank(); self.width];
+        self.grid.drain(self.bottom - n..self.bottom);
+        for _ in 0..n {
+            self.grid.insert(self.top, row.clone());
+        }
+    }
+
+    fn linefeed(&mut self) {
+        if self.y + 1 == self.bottom {
+            self.scroll_up(1);
+        } else if self.y + 1 < self.height {
+            self.y += 1;
+        }
+    }
+
+    fn reverse_index(&mut self) {
+        if self.y == self.top {
+            self.scroll_down(1);
+        } else {
+            self.y = self.y.saturating_sub(1);
+        }
+    }
+
+    fn move_to(&mut self, x: usize, y: usize) {
+        self.x = x.min(self.width - 1);
+        self.y = y.min(self.height - 1);
+        self.wrap_pending = false;
+    }
+
+    fn save_cursor(&mut self) {
+        self.saved = Saved {
+            x: self.x,
+            y: self.y,
+            style: self.style,
+        };
+    }
+
+    fn restore_cursor(&mut self) {
+        let saved = self.saved;
+        self.style = saved.style;
+        self.move_to(saved.x, saved.y);
+    }
+
+    fn set_alternate(&mut self, on: bool) {
+        if on && self.main.is_none() {
+            let grid = vec![vec![blank(Style::default()); self.width]; self.height];
+            self.main = Some(std::mem::replace(&mut self.grid, grid));
+        } else if !on {
+            if let Some(main) = self.main.take() {
+                self.grid = main;
+            }
+        }
+    }
+
+    fn set_mode(&mut self, private: bool, params: &[u16], on: bool) {
+        if !private {
+            return;
+        }
+        for &mode in params {
+            match mode {
+                1 => self.application_cursor = on,
+                7 => self.autowrap = on,
+                25 => self.cursor_visible = on,
+                47 | 1047 => self.set_alternate(on),
+                1049 => {
+                    if on {
+                        self.save_cursor();
+                        self.set_alternate(true);
+                    } else {
+                        self.set_alternate(false);
+                        self.restore_cursor();
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn reset(&mut self) {
+        let title = std::mem::take(&mut self.title);
+        *self = Self::new(self.width, self.height);
+        self.title = title;
+    }
+}
+
+impl Perform for Screen {
+    fn print(&mut self, ch: char) {
+        if self.wrap_pending {
+            self.x = 0;
+            self.linefeed();
+            self.wrap_pending = false;
+        }
+        self.grid[self.y][self.x] = Cell::new(ch, self.style);
+        if self.x + 1 < self.width {
+            self.x += 1;
+        } else if self.autowrap {
+            self.wrap_pending = true;
+        }
+    }
+
+    fn execute(&mut self, byte: u8) {
+        match byte {
+            0x08 => self.move_to(self.x.saturating_sub(1), self.y),
+            b'\t' => self.move_to((self.x / TAB_WIDTH + 1) * TAB_WIDTH, self.y),
+            b'\n' | 0x0b | 0x0c => {
+                self.linefeed();
+                self.wrap_pending = false;
+            }
+            b'\r' => self.move_to(0, self.y),
+            _ => {}
+        }
+    }
+
+    fn csi(&mut self, params: &[u16], intermediates: &[u8], action: char) {
+        // first parameter, or `default` when missing or 0
+        let arg = |i: usize, default: usize| match params.get(i) {
+            Some(&n) if n > 0 => n as usize,
+            _ => default,
+        };
+        let private = intermediates.first() == Some(&b'?');
+        let (x, y) = (self.x, self.y);
+        match action {
+            'A' => {
+                let floor = if y >= self.top { self.top } else { 0 };
+                self.move_to(x, y.saturating_sub(arg(0, 1)).max(floor));
+            }
+            'B' | 'e' => {
+                let ceiling = if y < self.bottom {
+                    self.bottom
+                } else {
+                    self.height
+                };
+                self.move_to(x, (y + arg(0, 1)).min(ceiling - 1));
+            }
+            'C' | 'a' => self.move_to(x + arg(0, 1), y),
+            'D' => self.move_to(x.saturating_sub(arg(0, 1)), y),
+            'E' => self.move_to(0, y + arg(0, 1)),
+            'F' => self.move_to(0, y.saturating_sub(arg(0, 1))),
+            'G' | '`' => self.move_to(arg(0, 1) - 1, y),
+            'd' => self.move_to(x, arg(0, 1) - 1),
+            'H' | 'f' => self.move_to(arg(1, 1) - 1, arg(0, 1) - 1),
+            'J' => {
+                let (from, to) = match params.first() {
+                    Some(0) | None => {
+                        self.erase(y, x..self.width);
+                        (y + 1, self.height)
+                    }
+                    Some(1) => {
+                        self.erase(y, 0..x + 1);
+                        (0, y)
+                    }
+                    _ => (0, self.height),
+                };
+                for row in from..to {
+                    self.erase(row, 0..self.width);
+                }
+            }
+            'K' => match params.first() {
+                Some(0) | None => self.erase(y, x..self.width),
+                Some(1) => self.erase(y, 0..x + 1),
+                _ => self.erase(y, 0..self.width),
+            },
+            '@' => {
+                let cell = self.blank();
+                let row = &mut self.grid[y];
+                for _ in 0..arg(0, 1).min(self.width - x) {
+                    row.insert(x, cell);
+                }
+                row.truncate(self.width);
+            }
+            'P' => {
+                let cell = self.blank();
+                let row = &mut self.grid[y];
+                row.drain(x..(x + arg(0, 1)).min(self.width));
+                row.resize(self.width, cell);
+            }
+            'X' => self.erase(y, x..x + arg(0, 1)),
+            'L' | 'M' if (self.top..self.bottom).contains(&y) => {
+                let top = std::mem::replace(&mut self.top, y);
+                if action == 'L' {
+                    self.scroll_down(arg(0, 1));
+                } else {
+                    self.scroll_up(arg(0, 1));
+                }
+                self.top = top;
+                self.move_to(0, y);
+            }
+            'S' if !private => self.scroll_up(arg(0, 1)),
+            'T' if intermediates.is_empty() => self.scroll_down(arg(0, 1)),
+            'm' if intermediates.is_empty() => self.style = self.style.apply_sgr(params),
+            'r' if intermediates.is_empty() => {
+                let (top, bottom) = (arg(0, 1) - 1, arg(1, self.height).min(self.height));
+                if top + 1 < bottom {
+                    self.top = top;
+                    self.bottom = bottom;
+                    self.move_to(0, 0);
+                }
+            }
+            's' if intermediates.is_empty() => self.save_cursor(),
+            'u' if intermediates.is_empty() => self.restore_cursor(),
+            'h' => self.set_mode(private, params, true),
+            'l' => self.set_mode(private, params, false),
+            'n' if intermediates.is_empty() => match params.first() {
+                Some(5) => self.responses.extend_from_slice(b"\x1b[0n"),
+                Some(6) => self
+                    .responses
+                    .extend(format!("\x1b[{};{}R", y + 1, x + 1).bytes()),
+                _ => {}
+            },
+            'c' if intermediates.is_empty() && params.iter().all(|&p| p == 0) => {
+                // a VT100 with advanced video
+                self.responses.extend_from_slice(b"\x1b[?1;2c");
+            }
+            _ => {}
+        }
+    }
+
+    fn esc(&mut self, intermediates: &[u8], action: char) {
+        if !intermediates.is_empty() {
+            // character set designations
+            return;
+        }
+        match action {
+            '7' => self.save_cursor(),
+            '8' => self.restore_cursor(),
+            'D' => self.linefeed(),
+            'E' => {
+                self.move_to(0, self.y);
+                self.linefeed();
+            }
+            'M' => self.reverse_index(),
+            'c' => self.reset(),
+            _ => {}
+        }
+    }
+
+    fn osc(&mut self, data: &[u8]) {
+        let data = String::from_utf8_lossy(data);
+        if let Some(title) = data.strip_prefix("0;").or_else(|| data.strip_prefix("2;")) {
+            self.title = title.to_owned();
+        }
+    }
+}
+
+fn blank(style: Style) -> Cell {
+    Cell::new(' ', style)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frame::style::Color;
+
+    fn run(width: usize, height: usize, output: &str) -> Screen {
+        let mut screen = Screen::new(width, height);
+        screen.process(output.as_bytes());
+        screen
+    }
+
+    fn rows(screen: &Screen) -> Vec<String> {
+        screen
+            .text()
+            .lines
+            .iter()
+            .map(|line| line.plain())
+            .collect()
+    }
+
+    #[test]
+    fn prints_and_wraps() {
+        let screen = run(4, 3, "abcdef\r\ngh");
+        assert_eq!(rows(&screen), ["abcd", "ef", "gh"]);
+        assert_eq!(screen.cursor(), (2, 2));
+    }
+
+    #[test]
+    fn scrolls_at_the_bottom() {
+        let screen = run(3, 2, "1\r\n2\r\n3");
+        assert_eq!(rows(&screen), ["2", "3"]);
+    }
+
+    #[test]
+    fn cursor_movement_and_erase() {
+        let screen = run(5, 3, "aaaaa\r\nbbbbb\x1b[1;3H\x1b[K\x1b[2;2H\x1b[1K");
+        assert_eq!(rows(&screen), ["aa", "  bbb", ""]);
+        assert_eq!(screen.cursor(), (1, 1));
+        assert_eq!(run(5, 3, "\x1b[99;99H").cursor(), (4, 2));
+    }
+
+    #[test]
+    fn scroll_region() {
+        // rows 2-3 scroll; rows 1 and 4 stay put
+        let screen = run(3, 4, "top\r\nx\r\ny\r\nbot\x1b[2;3r\x1b[3;1H\n\nz");
+        assert_eq!(rows(&screen), ["top", "", "z", "bot"]);
+    }
+
+    #[test]
+    fn scroll_region_reset() {
+        let screen = run(3, 3, "\x1b[1;2r\x1b[r\x1b[3;1Ha\nb");
+        assert_eq!(rows(&screen), ["", "a", " b"]);
+    }
+
+    #[test]
+    fn insert_and_delete_lines() {
+        let screen = run(3, 3, "a\r\nb\r\nc\x1b[2;1H\x1b[L");
+        assert_eq!(rows(&screen), ["a", "", "b"]);
+        let screen = run(3, 3, "a\r\nb\r\nc\x1b[1;1H\x1b[M");
+        assert_eq!(rows(&screen), ["b", "c", ""]);
+    }
+
+    #[test]
+    fn alternate_screen() {
+        let mut screen = run(4, 2, "main\x1b[2;2H");
+        screen.process(b"\x1b[?1049h\x1b[Halt");
+        assert_eq!(rows(&screen), ["alt", ""]);
+        screen.process(b"\x1b[?1049l");
+        assert_eq!(rows(&screen), ["main", ""]);
+        assert_eq!(screen.cursor(), (1, 1));
+    }
+
+    #[test]
+    fn status_and_position_reports() {
+        let mut screen = run(10, 5, "\x1b[3;4H\x1b[6n\x1b[5n\x1b[c");
+        assert_eq!(screen.take_responses(), b"\x1b[3;4R\x1b[0n\x1b[?1;2c");
+        assert!(screen.take_responses().is_empty());
+    }
+
+    #[test]
+    fn modes_and_title() {
+        let screen = run(4, 2, "\x1b[?25l\x1b[?1h\x1b]0;hello\x07");
+        assert!(!screen.cursor_visible());
+        assert!(screen.application_cursor());
+        assert_eq!(screen.title(), "hello");
+    }
+
+    #[test]
+    fn styles_cells() {
+        let screen = run(4, 1, "\x1b[31ma\x1b[0mb");
+        assert_eq!(screen.rows()[0][0].style.fg, Some(Color::DarkRed));
+        assert!(screen.rows()[0][1].style.is_plain());
+    }
+
+    #[test]
+    fn resize_keeps_cursor_line() {
+        let mut screen = run(3, 4, "1\r\n2\r\n3\r\n4");
+        screen.resize(2, 2);
+        assert_eq!(rows(&screen), ["3", "4"]);
+        assert_eq!(screen.cursor(), (1, 1));
+    }
+}
@@ -0,0 +1,217 @@
+use super::parser::{Parser, Perform};
+use crate::frame::rendered::Cell;
+use crate::frame::style::Style;
+use crate::frame::text::{Line, Span, Text};
+
+/// Columns between tab stops.
+const TAB_WIDTH: usize = 8;
+
+/// Furthest column cursor movement can reach past the end of a line, so a stream of
+/// large moves can't make lines grow faster than the output.
+const MAX_COLUMNS: usize = 4096;
+
+/// Captured command output interpreted like a terminal with no width limit and no
+/// bottom: `\r` goes back to overwrite the line, cursor movement edits earlier lines,
+/// erase sequences clear and SGR sets colors. Other sequences are dropped.
+///
+/// Unlike a real terminal a line feed also returns to the first column, since output
+/// written to a pipe doesn't go through the terminal's newline translation.
+#[derive(Clone, Debug, Default)]
+pub struct Transcript {
+    lines: Vec<Vec<Cell>>,
+    x: usize,
+    y: usize,
+    style: Style,
+    parser: Parser,
+}
+
+impl Transcript {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Interpret a chunk of output.
+    pub fn process(&mut self, bytes: &[u8]) {
+        let mut parser = std::mem::take(&mut self.parser);
+        parser.advance(bytes, self);
+        self.parser = parser;
+    }
+
+    /// Cursor position as (column, line).
+    pub fn cursor(&self) -> (usize, usize) {
+        (self.x, self.y)
+    }
+
+    /// The output so far, one line per line of output.
+    pub fn text(&self) -> Text {
+        let mut text = Text::default();
+        for row in &self.lines {
+            let mut line = Line::default();
+            for cell in row {
+                match line.spans.last_mut() {
+                    Some(span) if span.style == cell.style => span.text.push(cell.ch),
+                    _ => line.push(Span::styled(&cell.ch.to_string(), cell.style)),
+                }
+            }
+            text.push(line);
+        }
+        text
+    }
+
+    /// The cursor's line, created if the cursor moved past the end.
+    fn line(&mut self) -> &mut Vec<Cell> {
+        if self.lines.len() <= self.y {
+            self.lines.resize(self.y + 1, vec![]);
+        }
+        &mut self.lines[self.y]
+    }
+
+    /// Move the cursor to column `x`, at most `MAX_COLUMNS` or the end of the line.
+    fn move_x(&mut self, x: usize) {
+        let len = self.lines.get(self.y).map_or(0, Vec::len);
+        self.x = x.min(len.max(MAX_COLUMNS - 1));
+    }
+
+    /// Move the cursor to line `y`, at most one past the last line: like a terminal at
+    /// the bottom of the screen, moving down doesn't add lines.
+    fn move_y(&mut self, y: usize) {
+        self.y = y.min(self.lines.len());
+    }
+
+    /// Blank the first `end` cells of the cursor's line.
+    fn blank_to(&mut self, end: usize) {
+        let line = self.line();
+        let end = end.min(line.len());
+        for cell in &mut line[..end] {
+            *cell = Cell::new(' ', Style::default());
+        }
+    }
+}
+
+impl Perform for Transcript {
+    fn print(&mut self, ch: char) {
+        let (x, style) = (self.x, self.style);
+        let line = self.line();
+        if line.len() <= x {
+            line.resize(x + 1, Cell::new(' ', Style::default()));
+        }
+        line[x] = Cell::new(ch, style);
+        self.x += 1;
+    }
+
+    fn execute(&mut self, byte: u8) {
+        match byte {
+            0x08 => self.x = self.x.saturating_sub(1),
+            b'\t' => self.move_x((self.x / TAB_WIDTH + 1) * TAB_WIDTH),
+            b'\n' | 0x0b | 0x0c => {
+                // make sure an empty line still counts as a line
+                self.line();
+                self.y += 1;
+                self.x = 0;
+            }
+            b'\r' => self.x = 0,
+            _ => {}
+        }
+    }
+
+    fn csi(&mut self, params: &[u16], intermediates: &[u8], action: char) {
+        if !intermediates.is_empty() {
+            return;
+        }
+        // first parameter, or `default` when missing or 0
+        let arg = |i: usize, default: usize| match params.get(i) {
+            Some(&n) if n > 0 => n as usize,
+            _ => default,
+        };
+        match action {
+            'A' => self.y = self.y.saturating_sub(arg(0, 1)),
+            'B' | 'e' => self.move_y(self.y + arg(0, 1)),
+            'C' | 'a' => self.move_x(self.x + arg(0, 1)),
+            'D' => self.x = self.x.saturating_sub(arg(0, 1)),
+            'E' => {
+                self.move_y(self.y + arg(0, 1));
+                self.x = 0;
+            }
+            'F' => (self.x, self.y) = (0, self.y.saturating_sub(arg(0, 1))),
+            'G' | '`' => self.move_x(arg(0, 1) - 1),
+            'd' => self.move_y(arg(0, 1) - 1),
+            'H' | 'f' => {
+                self.move_y(arg(0, 1) - 1);
+                self.move_x(arg(1, 1) - 1);
+            }
+            'K' => match params.first() {
+                Some(0) | None => {
+                    let x = self.x;
+                    self.line().truncate(x);
+                }
+                Some(1) => self.blank_to(self.x + 1),
+                _ => self.line().clear(),
+            },
+            'J' => match params.first() {
+                Some(0) | None => {
+                    let x = self.x;
+                    self.line().truncate(x);
+                    self.lines.truncate(self.y + 1);
+                }
+                Some(1) => {
+                    for line in self.lines.iter_mut().take(self.y) {
+                        line.clear();
+                    }
+                    self.blank_to(self.x + 1);
+                }
+                _ => self.lines.iter_mut().for_each(Vec::clear),
+            },
+            'm' => self.style = self.style.apply_sgr(params),
+            _ => {}
+        }
+    }
+}
+
+/// Interpret `content` as terminal output; see `Transcript`.
+pub fn interpret(content: &str) -> Text {
+    let mut transcript = Transcript::new();
+    transcript.process(content.as_bytes());
+    transcript.text()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(content: &str) -> Vec<String> {
+        interpret(content)
+            .lines
+            .iter()
+            .map(|line| line.plain())
+            .collect()
+    }
+
+    #[test]
+    fn carriage_return_overwrites() {
+        assert_eq!(lines("10%\r50%\r100%\ndone\n"), ["100%", "done"]);
+        assert_eq!(lines("abc\r\x1b[Kx"), ["x"]);
+    }
+
+    #[test]
+    fn cursor_up_edits_earlier_lines() {
+        assert_eq!(lines("a\nb\n\x1b[2A\x1b[2Cz"), ["a z", "b"]);
+    }
+
+    #[test]
+    fn keeps_empty_lines() {
+        assert_eq!(lines("a\n\nb"), ["a", "", "b"]);
+    }
+
+    #[test]
+    fn large_moves_are_clamped() {
+        let mut transcript = Transcript::new();
+        for _ in 0..100 {
+            transcript.process(b"\x1b[65535C\x1b[65535Bx");
+        }
+        let (x, y) = transcript.cursor();
+        assert!(x <= MAX_COLUMNS + 100, "{x}");
+        assert!(y <= 100, "{y}");
+        transcript.process(b"\x1b[9999;9999Hy");
+        assert!(transcript.cursor().1 <= 101);
+    }
+}
@@ -0,0 +1,145 @@
+//! Re-run a command periodically and frame its output, like `watch(1)`.
+
+use crate::frame::style::Style;
+use crate::frame::text::{Line, Span, Text};
+use anyhow::{bail, Context, Result};
+use std::process::{Command, ExitStatus, Stdio};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// The outcome of one run of the watched command.
+#[derive(Clone, Debug)]
+pub struct RunResult {
+    /// Stdout followed by stderr.
+    pub output: String,
+    pub status: ExitStatus,
+    pub started: SystemTime,
+    pub duration: Duration,
+}
+
+impl RunResult {
+    /// Short description of the exit status, e.g. `exit 0`.
+    pub fn status_text(&self) -> String {
+        match self.status.code() {
+            Some(code) => format!("exit {}", code),
+            None => "killed by signal".to_owned(),
+        }
+    }
+}
+
+/// Runs a shell command on a background thread, waiting `interval` between runs.
+pub struct Watcher {
+    command: String,
+    interval: Duration,
+    receiver: Receiver<Result<RunResult>>,
+}
+
+impl Watcher {
+    /// Start running `command` through the shell; the first run starts immediately.
+    pub fn spawn(command: &str, interval: Duration) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let shell_command = command.to_owned();
+        std::thread::spawn(move || loop {
+            if sender.send(run(&shell_command)).is_err() {
+                break;
+            }
+            std::thread::sleep(interval);
+        });
+        Self {
+            command: command.to_owned(),
+            interval,
+            receiver,
+        }
+    }
+
+    pub fn command(&self) -> &str {
+        &self.command
+    }
+
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// The latest finished run since the last poll, if any.
+    pub fn poll(&self) -> Result<Option<RunResult>> {
+        let mut latest = None;
+        loop {
+            match self.receiver.try_recv() {
+                Ok(result) => latest = Some(result?),
+                Err(TryRecvError::Empty) => return Ok(latest),
+                Err(TryRecvError::Disconnected) => {
+                    bail!("command runner stopped unexpectedly")
+                }
+            }
+        }
+    }
+}
+
+/// Run `command` once through the shell, capturing its output.
+pub fn run(command: &str) -> Result<RunResult> {
+    let started = SystemTime::now();
+    let start = Instant::now();
+    // like watch(1), don't let the command read the terminal the frame is drawn on
+    let output = shell(command)
+        .stdin(Stdio::null())
+        .output()
+        .with_context(|| format!("cannot run '{}'", command))?;
+    let mut text = String::from_utf8_lossy(&output.stdout).into_owned();
+    text.push_str(&String::from_utf8_lossy(&output.stderr));
+    Ok(RunResult {
+        output: text,
+        status: output.status,
+        started,
+        duration: start.elapsed(),
+    })
+}
+
+#[cfg(unix)]
+fn shell(command: &str) -> Command {
+    let mut cmd = Command::new("sh");
+    cmd.arg("-c").arg(command);
+    cmd
+}
+
+#[cfg(windows)]
+fn shell(command: &str) -> Command {
+    let mut cmd = Command::new("cmd");
+    cmd.arg("/C").arg(command);
+    cmd
+}
+
+/// `current` as frame content, with characters that differ from `previous` at the same
+/// line and column drawn in `style`.
+pub fn highlight_changes(previous: &str, current: &str, style: Style) -> Text {
+    let mut old_lines = previous.lines();
+    let mut text = Text::default();
+    for line in current.lines() {
+        let old: Vec<char> = old_lines.next().unwrap_or("").chars().collect();
+        let mut spans: Vec<Span> = vec![];
+        for (i, ch) in line.chars().enumerate() {
+            let changed = old.get(i) != Some(&ch);
+            let span_style = if changed { style } else { Style::default() };
+            match spans.last_mut() {
+                Some(span) if span.style == span_style => span.text.push(ch),
+                _ => spans.push(Span::styled(&ch.to_string(), span_style)),
+            }
+        }
+        text.push(Line::new(spans));
+    }
+    text
+}
+
+/// Wall-clock time of day in UTC, as `HH:MM:SS UTC`. watch(1) shows local time, but
+/// the standard library has no time zones, so the time is labelled instead.
+pub fn utc_time_of_day(time: SystemTime) -> String {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    format!(
+        "{:02}:{:02}:{:02} UTC",
+        secs / 3600 % 24,
+        secs / 60 % 60,
+        secs % 60
+    )
+}
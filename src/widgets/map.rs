@@ -0,0 +1,325 @@
+use crate::frame::style::Style;
+use crate::frame::text::Text;
+use crate::geo::{
+    canvas_string, canvas_text, is_land, normalize_lon, place_marker, Canvas, LatLon, Marker,
+};
+
+/// How latitude/longitude are laid out on the flat map.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Projection {
+    /// Longitude and latitude map linearly to x and y.
+    Equirectangular,
+    /// Conformal projection; latitudes are clipped to ±85°.
+    Mercator,
+}
+
+/// How many map samples each character cell holds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MapResolution {
+    /// One sample per cell, drawn with `land_char`.
+    Ascii,
+    /// 2x4 samples per cell, drawn with braille dots.
+    Braille,
+}
+
+impl MapResolution {
+    fn subcells(&self) -> (usize, usize) {
+        match self {
+            MapResolution::Ascii => (1, 1),
+            MapResolution::Braille => (2, 4),
+        }
+    }
+}
+
+/// A route drawn between two points along the great circle joining them.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Route {
+    pub from: LatLon,
+    pub to: LatLon,
+    pub style: Style,
+}
+
+const MERCATOR_MAX_LAT: f32 = 85.0;
+
+/// Flat world map with plotted points, routes and labels.
+#[derive(Clone, Debug)]
+pub struct WorldMap {
+    pub width: usize,
+    pub height: usize,
+    pub projection: Projection,
+    pub resolution: MapResolution,
+    pub land_char: char,
+    pub land_style: Style,
+    /// Character for route segments in ASCII resolution.
+    pub route_char: char,
+    pub markers: Vec<Marker>,
+    pub routes: Vec<Route>,
+}
+
+impl Default for WorldMap {
+    fn default() -> Self {
+        Self {
+            width: 72,
+            height: 18,
+            projection: Projection::Equirectangular,
+            resolution: MapResolution::Ascii,
+            land_char: '#',
+            land_style: Style::default(),
+            route_char: '.',
+            markers: vec![],
+            routes: vec![],
+        }
+    }
+}
+
+impl WorldMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Size the map to fill `width` x `height` cells, e.g. a frame's content area.
+    pub fn with_size(mut self, width: usize, height: usize) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    pub fn with_projection(mut self, projection: Projection) -> Self {
+        self.projection = projection;
+        self
+    }
+
+    pub fn with_resolution(mut self, resolution: MapResolution) -> Self {
+        self.resolution = resolution;
+        self
+    }
+
+    pub fn with_land_char(mut self, land_char: char) -> Self {
+        self.land_char = land_char;
+        self
+    }
+
+    pub fn with_land_style(mut self, land_style: Style) -> Self {
+        self.land_style = land_style;
+        self
+    }
+
+    pub fn with_marker(mut self, marker: Marker) -> Self {
+        self.markers.push(marker);
+        self
+    }
+
+    pub fn with_route(mut self, from: LatLon, to: LatLon, style: Style) -> Self {
+        self.routes.push(Route { from, to, style });
+        self
+    }
+
+    /// Map `lat`/`lon` to fractional (x, y) in `0.0..1.0`.
+    fn project(&self, lat: f32, lon: f32) -> (f32, f32) {
+        let x = (normalize_lon(lon) + 180.0) / 360.0;
+        let y = match self.projection {
+            Projection::Equirectangular => (90.0 - lat) / 180.0,
+            Projection::Mercator => {
+                let max = mercator_y(MERCATOR_MAX_LAT);
+                (max - mercator_y(lat)) / (2.0 * max)
+            }
+        };
+        (x, y.clamp(0.0, 1.0))
+    }
+
+    /// Inverse of `project`.
+    fn unproject(&self, x: f32, y: f32) -> (f32, f32) {
+        let lon = x * 360.0 - 180.0;
+        let lat = match self.projection {
+            Projection::Equirectangular => 90.0 - y * 180.0,
+            Projection::Mercator => {
+                let max = mercator_y(MERCATOR_MAX_LAT);
+                let m = max - y * 2.0 * max;
+                (2.0 * m.exp().atan() - std::f32::consts::FRAC_PI_2).to_degrees()
+            }
+        };
+        (lat, lon)
+    }
+
+    /// Cell containing `lat`/`lon`.
+    pub fn cell(&self, lat: f32, lon: f32) -> (usize, usize) {
+        let (x, y) = self.project(lat, lon);
+        (
+            ((x * self.width as f32) as usize).min(self.width.saturating_sub(1)),
+            ((y * self.height as f32) as usize).min(self.height.saturating_sub(1)),
+        )
+    }
+
+    pub fn render(&self) -> String {
+        canvas_string(&self.canvas())
+    }
+
+    /// Render with land, route and marker styles.
+    pub fn render_text(&self) -> Text {
+        canvas_text(&self.canvas())
+    }
+
+    fn canvas(&self) -> Canvas {
+        if self.width == 0 || self.height == 0 {
+            return vec![];
+        }
+        let (sx, sy) = self.resolution.subcells();
+        let (w, h) = (self.width * sx, self.height * sy);
+        // sub-cell grid: 0 = empty, 1 = land, 2 = route
+        let mut dots = vec![vec![0u8; w]; h];
+        let mut route_styles = vec![vec![None; self.width]; self.height];
+        for (y, row) in dots.iter_mut().enumerate() {
+            for (x, dot) in row.iter_mut().enumerate() {
+                let (lat, lon) =
+                    self.unproject((x as f32 + 0.5) / w as f32, (y as f32 + 0.5) / h as f32);
+                if is_land(lat, lon) {
+                    *dot = 1;
+                }
+            }
+        }
+        for route in &self.routes {
+            for (lat, lon) in great_circle(route.from, route.to, w.max(h) * 2) {
+                let (x, y) = self.project(lat, lon);
+                let (dx, dy) = (
+                    ((x * w as f32) as usize).min(w - 1),
+                    ((y * h as f32) as usize).min(h - 1),
+                );
+                dots[dy][dx] = 2;
+                route_styles[dy / sy][dx / sx] = Some(route.style);
+            }
+        }
+
+        let mut canvas: Canvas = vec![vec![(' ', Style::default()); self.width]; self.height];
+        for (row, line) in canvas.iter_mut().enumerate() {
+            for (col, cell) in line.iter_mut().enumerate() {
+                let sub = |x: usize, y: usize| dots[row * sy + y][col * sx + x];
+                let route_style = route_styles[row][col];
+                *cell = match self.resolution {
+                    MapResolution::Ascii => match sub(0, 0) {
+                        2 => (self.route_char, route_style.unwrap_or_default()),
+                        1 => (self.land_char, self.land_style),
+                        _ => (' ', Style::default()),
+                    },
+                    MapResolution::Braille => {
+                        let mut bits = 0u32;
+                        for (i, (x, y)) in BRAILLE_DOTS.iter().enumerate() {
+                            if sub(*x, *y) != 0 {
+                                bits |= 1 << i;
+                            }
+                        }
+                        let ch = char::from_u32(0x2800 + bits).unwrap_or(' ');
+                        let style = route_style.unwrap_or(self.land_style);
+                        if bits == 0 {
+                            (' ', Style::default())
+                        } else {
+                            (ch, style)
+                        }
+                    }
+                };
+            }
+        }
+
+        for marker in &self.markers {
+            let (col, row) = self.cell(marker.position.lat, marker.position.lon);
+            place_marker(&mut canvas, marker, col, row);
+        }
+        canvas
+    }
+}
+
+/// (column, row) of each braille dot, in bit order.
+const BRAILLE_DOTS: [(usize, usize); 8] = [
+    (0, 0),
+    (0, 1),
+    (0, 2),
+    (1, 0),
+    (1, 1),
+    (1, 2),
+    (0, 3),
+    (1, 3),
+];
+
+fn mercator_y(lat: f32) -> f32 {
+    let lat = lat.clamp(-MERCATOR_MAX_LAT, MERCATOR_MAX_LAT).to_radians();
+    (std::f32::consts::FRAC_PI_4 + lat / 2.0).tan().ln()
+}
+
+/// `steps + 1` points along the great circle from `a` to `b`, as (lat, lon).
+fn great_circle(a: LatLon, b: LatLon, steps: usize) -> Vec<(f32, f32)> {
+    let to_vec = |p: LatLon| {
+        let (lat, lon) = (p.lat.to_radians(), p.lon.to_radians());
+        (lat.cos() * lon.cos(), lat.cos() * lon.sin(), lat.sin())
+    };
+    let (p, q) = (to_vec(a), to_vec(b));
+    let dot = (p.0 * q.0 + p.1 * q.1 + p.2 * q.2).clamp(-1.0, 1.0);
+    let omega = dot.acos();
+    let steps = steps.max(1);
+    if omega > std::f32::consts::PI - 1e-3 {
+        // every great circle through `a` reaches its antipode, so the path is ambiguous
+        // and slerp divides by ~0: go over a pole, or along the equator from a pole
+        let via = if a.lat.abs() < 45.0 {
+            LatLon::new(if a.lat >= 0.0 { 90.0 } else { -90.0 }, a.lon)
+        } else {
+            LatLon::new(0.0, a.lon)
+        };
+        let mut points = great_circle(a, via, steps / 2);
+        points.pop();
+        points.extend(great_circle(via, b, steps - steps / 2));
+        return points;
+    }
+    (0..=steps)
+        .map(|i| {
+            let t = i as f32 / steps as f32;
+            let (s, u) = if omega.abs() < 1e-6 {
+                (1.0 - t, t)
+            } else {
+                (
+                    ((1.0 - t) * omega).sin() / omega.sin(),
+                    (t * omega).sin() / omega.sin(),
+                )
+            };
+            let (x, y, z) = (s * p.0 + u * q.0, s * p.1 + u * q.1, s * p.2 + u * q.2);
+            (
+                z.atan2((x * x + y * y).sqrt()).to_degrees(),
+                y.atan2(x).to_degrees(),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_on_sphere(points: &[(f32, f32)]) {
+        for &(lat, lon) in points {
+            assert!(lat.is_finite() && lon.is_finite(), "{lat}, {lon}");
+            assert!((-90.0..=90.0).contains(&lat) && (-180.0..=180.0).contains(&lon));
+        }
+    }
+
+    #[test]
+    fn great_circle_ends() {
+        let points = great_circle(LatLon::new(10.0, 20.0), LatLon::new(-30.0, 100.0), 8);
+        assert_eq!(points.len(), 9);
+        assert_on_sphere(&points);
+        let (first, last) = (points[0], points[8]);
+        assert!((first.0 - 10.0).abs() < 1e-3 && (first.1 - 20.0).abs() < 1e-3);
+        assert!((last.0 + 30.0).abs() < 1e-3 && (last.1 - 100.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn great_circle_antipodal() {
+        for (a, b) in [
+            (LatLon::new(0.0, 0.0), LatLon::new(0.0, 180.0)),
+            (LatLon::new(20.0, -40.0), LatLon::new(-20.0, 140.0)),
+            (LatLon::new(90.0, 0.0), LatLon::new(-90.0, 0.0)),
+        ] {
+            let points = great_circle(a, b, 9);
+            assert_eq!(points.len(), 10);
+            assert_on_sphere(&points);
+            assert!((points[0].0 - a.lat).abs() < 1e-3);
+            assert!((points[9].0 - b.lat).abs() < 1e-3);
+        }
+    }
+}
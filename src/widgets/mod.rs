@@ -0,0 +1,11 @@
+//! Widgets that produce frame content.
+
+pub mod map;
+pub mod multi;
+pub mod progress;
+pub mod spinner;
+
+pub use map::{MapResolution, Projection, WorldMap};
+pub use multi::{MultiProgress, TaskHandle};
+pub use progress::{BarGlyphs, ProgressBar};
+pub use spinner::Spinner;
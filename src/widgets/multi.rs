@@ -0,0 +1,263 @@
+use crate::frame::style::Style;
+use crate::frame::text::{Line, Span, Text};
+use crate::frame::{FrameEngine, FrameRender};
+use crate::widgets::progress::{format_duration, ProgressBar};
+use crate::widgets::spinner::Spinner;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+/// Finished tasks named in the summary line; the rest are only counted.
+const SUMMARY_LABELS: usize = 3;
+
+/// A stack of labeled progress bars and spinners drawn together in one frame.
+///
+/// Cloning shares the same stack, and task handles can be moved to other threads;
+/// whoever owns the `FrameEngine` calls `draw` to show the current state.
+/// Finished tasks are removed from the stack and counted in a summary line.
+#[derive(Clone, Default)]
+pub struct MultiProgress {
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Default)]
+struct State {
+    tasks: Vec<Task>,
+    next_id: usize,
+    label_style: Style,
+    summary_style: Style,
+}
+
+struct Task {
+    id: usize,
+    label: String,
+    widget: Widget,
+    started: Instant,
+    finished: Option<Instant>,
+}
+
+enum Widget {
+    Bar(ProgressBar),
+    Spinner(Spinner),
+}
+
+/// A handle to one task in a `MultiProgress`.
+#[derive(Clone)]
+pub struct TaskHandle {
+    id: usize,
+    state: Arc<Mutex<State>>,
+}
+
+impl MultiProgress {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_label_style(self, style: Style) -> Self {
+        self.lock().label_style = style;
+        self
+    }
+
+    pub fn with_summary_style(self, style: Style) -> Self {
+        self.lock().summary_style = style;
+        self
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        // a panicking producer shouldn't take the display down with it
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn add(&self, label: &str, widget: Widget) -> TaskHandle {
+        let mut state = self.lock();
+        let id = state.next_id;
+        state.next_id += 1;
+        state.tasks.push(Task {
+            id,
+            label: label.to_owned(),
+            widget,
+            started: Instant::now(),
+            finished: None,
+        });
+        TaskHandle {
+            id,
+            state: self.state.clone(),
+        }
+    }
+
+    /// Add a progress bar to the bottom of the stack.
+    pub fn add_bar(&self, label: &str, bar: ProgressBar) -> TaskHandle {
+        self.add(label, Widget::Bar(bar))
+    }
+
+    /// Add a spinner to the bottom of the stack.
+    pub fn add_spinner(&self, label: &str, spinner: Spinner) -> TaskHandle {
+        self.add(label, Widget::Spinner(spinner))
+    }
+
+    /// Number of tasks still running.
+    pub fn active(&self) -> usize {
+        self.lock()
+            .tasks
+            .iter()
+            .filter(|t| t.finished.is_none())
+            .count()
+    }
+
+    /// True once every task has finished.
+    pub fn is_done(&self) -> bool {
+        self.active() == 0
+    }
+
+    /// One line per running task, followed by a summary of finished ones.
+    pub fn text(&self) -> Text {
+        let state = self.lock();
+        let label_width = state
+            .tasks
+            .iter()
+            .filter(|t| t.finished.is_none())
+            .map(|t| t.label.chars().count())
+            .max()
+            .unwrap_or(0);
+        let mut text = Text::default();
+        for task in state.tasks.iter().filter(|t| t.finished.is_none()) {
+            let mut line = Line::default();
+            if label_width > 0 {
+                line.push(Span::styled(
+                    &format!("{:<width$} ", task.label, width = label_width),
+                    state.label_style,
+                ));
+            }
+            let widget = match &task.widget {
+                Widget::Bar(bar) => bar.line(),
+                Widget::Spinner(spinner) => spinner.line(),
+            };
+            line.spans.extend(widget.spans);
+            text.push(line);
+        }
+
+        let finished: Vec<&Task> = state
+            .tasks
+            .iter()
+            .filter(|t| t.finished.is_some())
+            .collect();
+        if !finished.is_empty() {
+            // from the first task starting to the last one finishing
+            let first_start = state.tasks.iter().map(|t| t.started).min();
+            let last_finish = finished.iter().filter_map(|t| t.finished).max();
+            let span = match (first_start, last_finish) {
+                (Some(start), Some(end)) => end.saturating_duration_since(start),
+                _ => Duration::ZERO,
+            };
+            let mut labels = finished
+                .iter()
+                .take(SUMMARY_LABELS)
+                .map(|t| t.label.as_str())
+                .collect::<Vec<&str>>()
+                .join(", ");
+            if finished.len() > SUMMARY_LABELS {
+                labels.push_str(&format!(" and {} more", finished.len() - SUMMARY_LABELS));
+            }
+            text.push(Line::new(vec![Span::styled(
+                &format!(
+                    "✔ {} of {} done in {}: {}",
+                    finished.len(),
+                    state.tasks.len(),
+                    format_duration(span),
+                    labels
+                ),
+                state.summary_style,
+            )]));
+        }
+        text
+    }
+
+    /// Draw the stack into `engine`, shrinking or growing the frame to fit it.
+    pub fn draw<R: FrameRender>(&self, engine: &mut FrameEngine<R>) {
+        let text = self.text();
+        let width = engine.config().width;
+        engine.resize(width, text.lines.len().max(1) + engine.framespace());
+        engine.update_text(&text);
+    }
+}
+
+impl TaskHandle {
+    fn with_task<T>(&self, f: impl FnOnce(&mut Task) -> T) -> Option<T> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.tasks.iter_mut().find(|t| t.id == self.id).map(f)
+    }
+
+    fn with_bar(&self, f: impl FnOnce(&mut ProgressBar)) {
+        self.with_task(|task| {
+            if let Widget::Bar(bar) = &mut task.widget {
+                f(bar)
+            }
+        });
+    }
+
+    /// Set the position of a progress bar; ignored for spinners.
+    pub fn set_position(&self, position: u64) {
+        self.with_bar(|bar| bar.set_position(position));
+    }
+
+    /// Advance a progress bar; ignored for spinners.
+    pub fn inc(&self, delta: u64) {
+        self.with_bar(|bar| bar.inc(delta));
+    }
+
+    /// Set the total of a progress bar; ignored for spinners.
+    pub fn set_total(&self, total: Option<u64>) {
+        self.with_bar(|bar| bar.set_total(total));
+    }
+
+    pub fn set_message(&self, message: &str) {
+        self.with_task(|task| match &mut task.widget {
+            Widget::Bar(bar) => bar.set_message(message),
+            Widget::Spinner(spinner) => spinner.set_message(message),
+        });
+    }
+
+    /// Mark the task done, moving it into the summary line.
+    pub fn finish(&self) {
+        self.with_task(|task| {
+            if let Widget::Bar(bar) = &mut task.widget {
+                bar.finish();
+            }
+            task.finished.get_or_insert_with(Instant::now);
+        });
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.with_task(|task| task.finished.is_some())
+            .unwrap_or(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summary_names_a_few_tasks_and_spans_all_of_them() {
+        let multi = MultiProgress::new();
+        for label in ["a", "b", "c", "d", "e", "running"] {
+            multi.add_bar(label, ProgressBar::new(10));
+        }
+        let now = Instant::now();
+        {
+            // staggered runs: the first starts 90s ago, none took longer than 30s
+            let mut state = multi.lock();
+            for (i, task) in state.tasks.iter_mut().enumerate() {
+                task.started = now - Duration::from_secs(90 - i as u64 * 15);
+                if task.label != "running" {
+                    task.finished = Some(task.started + Duration::from_secs(30));
+                }
+            }
+        }
+        let text = multi.text();
+        assert_eq!(text.lines.len(), 2);
+        assert_eq!(
+            text.lines[1].plain(),
+            "✔ 5 of 6 done in 1m30s: a, b, c and 2 more"
+        );
+    }
+}
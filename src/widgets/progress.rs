@@ -0,0 +1,416 @@
+use crate::animations::Animation;
+use crate::frame::style::Style;
+use crate::frame::text::{Line, Span};
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// Characters used to draw a progress bar.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BarGlyphs {
+    pub left: String,
+    pub right: String,
+    pub filled: char,
+    pub empty: char,
+    /// Partially filled cells, from least to most filled; empty for whole-cell precision.
+    pub partial: Vec<char>,
+}
+
+impl Default for BarGlyphs {
+    /// Block characters with eighth-cell precision.
+    fn default() -> Self {
+        Self {
+            left: "▕".to_owned(),
+            right: "▏".to_owned(),
+            filled: '█',
+            empty: ' ',
+            partial: "▏▎▍▌▋▊▉".chars().collect(),
+        }
+    }
+}
+
+impl BarGlyphs {
+    /// Plain ASCII: `[=====>    ]`.
+    pub fn ascii() -> Self {
+        Self {
+            left: "[".to_owned(),
+            right: "]".to_owned(),
+            filled: '=',
+            empty: ' ',
+            partial: vec!['>'],
+        }
+    }
+
+    /// Draw `fraction` of a `width`-cell bar.
+    pub fn bar(&self, fraction: f64, width: usize) -> String {
+        let steps = self.partial.len() + 1;
+        let total = (fraction.clamp(0.0, 1.0) * (width * steps) as f64).round() as usize;
+        let (full, rem) = (total / steps, total % steps);
+        let mut bar = String::with_capacity(width * 3);
+        bar.extend(std::iter::repeat_n(self.filled, full));
+        if full < width {
+            bar.push(if rem > 0 {
+                self.partial[rem - 1]
+            } else {
+                self.empty
+            });
+            bar.extend(std::iter::repeat_n(self.empty, width - full - 1));
+        }
+        bar
+    }
+}
+
+/// How many recent updates the rate is estimated from.
+const RATE_SAMPLES: usize = 16;
+/// Updates older than this are dropped from the rate estimate, and a bar that hasn't
+/// moved for this long shows no rate at all.
+const RATE_WINDOW: Duration = Duration::from_secs(10);
+/// Rates over shorter spans than this are too noisy to show.
+const RATE_MIN_SPAN: Duration = Duration::from_millis(50);
+
+/// A progress bar for one line of frame content, with percentage, throughput and ETA.
+///
+/// Without a total the bar is indeterminate and shows the `Animation::Loading` spinner.
+#[derive(Clone, Debug)]
+pub struct ProgressBar {
+    pub total: Option<u64>,
+    pub position: u64,
+    /// Width of the bar itself, in cells.
+    pub width: usize,
+    pub message: String,
+    pub unit: String,
+    pub glyphs: BarGlyphs,
+    pub bar_style: Style,
+    pub show_percent: bool,
+    pub show_count: bool,
+    pub show_rate: bool,
+    pub show_eta: bool,
+    started: Instant,
+    finished: Option<Instant>,
+    samples: VecDeque<(Instant, u64)>,
+}
+
+impl ProgressBar {
+    pub fn new(total: u64) -> Self {
+        let started = Instant::now();
+        Self {
+            total: Some(total),
+            position: 0,
+            width: 30,
+            message: String::new(),
+            unit: String::new(),
+            glyphs: BarGlyphs::default(),
+            bar_style: Style::default(),
+            show_percent: true,
+            show_count: true,
+            show_rate: true,
+            show_eta: true,
+            started,
+            finished: None,
+            samples: VecDeque::from([(started, 0)]),
+        }
+    }
+
+    /// A bar with no known total.
+    pub fn indeterminate() -> Self {
+        Self {
+            total: None,
+            ..Self::new(0)
+        }
+    }
+
+    pub fn with_width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn with_message(mut self, message: &str) -> Self {
+        self.message = message.to_owned();
+        self
+    }
+
+    /// Unit shown after counts and rates, e.g. `B` or `files`.
+    pub fn with_unit(mut self, unit: &str) -> Self {
+        self.unit = unit.to_owned();
+        self
+    }
+
+    pub fn with_glyphs(mut self, glyphs: BarGlyphs) -> Self {
+        self.glyphs = glyphs;
+        self
+    }
+
+    pub fn with_bar_style(mut self, bar_style: Style) -> Self {
+        self.bar_style = bar_style;
+        self
+    }
+
+    pub fn with_percent(mut self, show: bool) -> Self {
+        self.show_percent = show;
+        self
+    }
+
+    pub fn with_count(mut self, show: bool) -> Self {
+        self.show_count = show;
+        self
+    }
+
+    pub fn with_rate(mut self, show: bool) -> Self {
+        self.show_rate = show;
+        self
+    }
+
+    pub fn with_eta(mut self, show: bool) -> Self {
+        self.show_eta = show;
+        self
+    }
+
+    pub fn set_message(&mut self, message: &str) {
+        self.message = message.to_owned();
+    }
+
+    pub fn set_total(&mut self, total: Option<u64>) {
+        self.total = total;
+    }
+
+    pub fn set_position(&mut self, position: u64) {
+        self.set_position_at(position, Instant::now());
+    }
+
+    pub fn set_position_at(&mut self, position: u64, now: Instant) {
+        self.position = position;
+        // only progress counts, so repeated updates don't hide a stall
+        if self.samples.back().is_some_and(|(_, p)| *p == position) {
+            return;
+        }
+        self.samples.push_back((now, position));
+        while self.samples.len() > RATE_SAMPLES
+            || self
+                .samples
+                .front()
+                .is_some_and(|(t, _)| now.saturating_duration_since(*t) > RATE_WINDOW)
+                && self.samples.len() > 2
+        {
+            self.samples.pop_front();
+        }
+    }
+
+    pub fn inc(&mut self, delta: u64) {
+        self.set_position(self.position.saturating_add(delta));
+    }
+
+    /// Mark the task done; a determinate bar is filled to its total.
+    pub fn finish(&mut self) {
+        if let Some(total) = self.total {
+            self.set_position(total);
+        }
+        self.finished = Some(Instant::now());
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished.is_some()
+    }
+
+    /// Completed fraction, or `None` when indeterminate.
+    pub fn fraction(&self) -> Option<f64> {
+        self.total.map(|total| {
+            if total == 0 {
+                1.0
+            } else {
+                (self.position as f64 / total as f64).min(1.0)
+            }
+        })
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.finished
+            .unwrap_or_else(Instant::now)
+            .saturating_duration_since(self.started)
+    }
+
+    /// Units per second over the recent updates, decaying while nothing moves.
+    pub fn rate(&self) -> f64 {
+        self.rate_at(Instant::now()).unwrap_or(0.0)
+    }
+
+    /// Rate from the oldest recent update until `now`; `None` until there's enough to go
+    /// on, and again once the bar has stalled for `RATE_WINDOW`.
+    fn rate_at(&self, now: Instant) -> Option<f64> {
+        let (t0, p0) = self.samples.front()?;
+        let (t1, p1) = self.samples.back()?;
+        let span = now.saturating_duration_since(*t0);
+        if t1.duration_since(*t0) < RATE_MIN_SPAN
+            || now.saturating_duration_since(*t1) > RATE_WINDOW
+        {
+            return None;
+        }
+        Some(p1.saturating_sub(*p0) as f64 / span.as_secs_f64())
+    }
+
+    /// Estimated time remaining, when the total and a rate are known.
+    pub fn eta(&self) -> Option<Duration> {
+        self.eta_at(Instant::now())
+    }
+
+    fn eta_at(&self, now: Instant) -> Option<Duration> {
+        let remaining = self.total?.saturating_sub(self.position);
+        if remaining == 0 {
+            return Some(Duration::ZERO);
+        }
+        Duration::try_from_secs_f64(remaining as f64 / self.rate_at(now)?).ok()
+    }
+
+    /// The bar and its statistics as a line of frame content.
+    pub fn line(&self) -> Line {
+        self.line_at(Instant::now())
+    }
+
+    fn line_at(&self, now: Instant) -> Line {
+        let mut line = Line::default();
+        if !self.message.is_empty() {
+            line.push(Span::raw(&format!("{} ", self.message)));
+        }
+        match self.fraction() {
+            Some(fraction) => {
+                line.push(Span::raw(&self.glyphs.left));
+                line.push(Span::styled(
+                    &self.glyphs.bar(fraction, self.width),
+                    self.bar_style,
+                ));
+                line.push(Span::raw(&self.glyphs.right));
+                if self.show_percent {
+                    line.push(Span::raw(&format!(" {:>3.0}%", fraction * 100.0)));
+                }
+            }
+            None => {
+                let frames = Animation::Loading.frames();
+                let idx = if self.is_finished() {
+                    0
+                } else {
+                    (now.saturating_duration_since(self.started).as_millis() / 80) as usize
+                        % frames.len()
+                };
+                line.push(Span::styled(frames[idx], self.bar_style));
+            }
+        }
+
+        let mut stats: Vec<String> = vec![];
+        if self.show_count {
+            stats.push(match self.total {
+                Some(total) => format!(
+                    "{}/{}{}",
+                    human_count(self.position as f64),
+                    human_count(total as f64),
+                    self.unit
+                ),
+                None => format!("{}{}", human_count(self.position as f64), self.unit),
+            });
+        }
+        if self.show_rate && !self.is_finished() {
+            stats.push(match self.rate_at(now) {
+                Some(rate) => format!("{}{}/s", human_count(rate), self.unit),
+                None => format!("--{}/s", self.unit),
+            });
+        }
+        if self.show_eta {
+            stats.push(if self.is_finished() || self.total.is_none() {
+                format_duration(self.elapsed())
+            } else {
+                match self.eta_at(now) {
+                    Some(eta) => format!("ETA {}", format_duration(eta)),
+                    None => "ETA --".to_owned(),
+                }
+            });
+        }
+        if !stats.is_empty() {
+            line.push(Span::raw(&format!(" {}", stats.join(" "))));
+        }
+        line
+    }
+}
+
+/// `1234` -> `1.2k`, with SI suffixes.
+pub(crate) fn human_count(n: f64) -> String {
+    const SUFFIXES: [&str; 5] = ["", "k", "M", "G", "T"];
+    let mut n = n;
+    let mut i = 0;
+    while n >= 1000.0 && i < SUFFIXES.len() - 1 {
+        n /= 1000.0;
+        i += 1;
+    }
+    if i == 0 && n.fract() == 0.0 {
+        format!("{}", n)
+    } else if n < 10.0 {
+        format!("{:.1}{}", n, SUFFIXES[i])
+    } else {
+        format!("{:.0}{}", n, SUFFIXES[i])
+    }
+}
+
+/// `75s` -> `1m15s`.
+pub(crate) fn format_duration(d: Duration) -> String {
+    let secs = d.as_secs();
+    if secs >= 3600 {
+        format!("{}h{:02}m", secs / 3600, secs % 3600 / 60)
+    } else if secs >= 60 {
+        format!("{}m{:02}s", secs / 60, secs % 60)
+    } else {
+        format!("{}s", secs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn secs(s: f64) -> Duration {
+        Duration::from_secs_f64(s)
+    }
+
+    #[test]
+    fn rate_and_eta_from_recent_updates() {
+        let mut bar = ProgressBar::new(100);
+        let t0 = bar.started;
+        assert_eq!(bar.rate_at(t0), None);
+        bar.set_position_at(10, t0 + secs(0.01));
+        assert_eq!(bar.rate_at(t0 + secs(0.01)), None, "too short to tell");
+        bar.set_position_at(50, t0 + secs(1.0));
+        assert_eq!(bar.rate_at(t0 + secs(1.0)), Some(50.0));
+        assert_eq!(bar.eta_at(t0 + secs(1.0)), Some(secs(1.0)));
+        bar.set_position_at(100, t0 + secs(2.0));
+        assert_eq!(bar.eta_at(t0 + secs(2.0)), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn stalled_bar_decays_then_shows_no_rate() {
+        let mut bar = ProgressBar::new(100).with_unit("B");
+        let t0 = bar.started;
+        bar.set_position_at(50, t0 + secs(1.0));
+        // repeating the position isn't progress
+        bar.set_position_at(50, t0 + secs(2.0));
+        assert_eq!(bar.rate_at(t0 + secs(2.0)), Some(25.0));
+        let stalled = t0 + secs(1.0) + RATE_WINDOW + secs(1.0);
+        assert_eq!(bar.rate_at(stalled), None);
+        assert_eq!(bar.eta_at(stalled), None);
+        let line = bar.line_at(stalled).plain();
+        assert!(line.ends_with(" 50/100B --B/s ETA --"), "{}", line);
+    }
+
+    #[test]
+    fn eighth_block_bar() {
+        let glyphs = BarGlyphs::default();
+        assert_eq!(glyphs.bar(0.0, 4), "    ");
+        assert_eq!(glyphs.bar(0.5, 4), "██  ");
+        assert_eq!(glyphs.bar(1.0 / 32.0, 4), "▏   ");
+        assert_eq!(glyphs.bar(2.0, 4), "████");
+        assert_eq!(BarGlyphs::ascii().bar(0.625, 4), "==> ");
+    }
+
+    #[test]
+    fn human_units() {
+        assert_eq!(human_count(999.0), "999");
+        assert_eq!(human_count(1234.0), "1.2k");
+        assert_eq!(human_count(56_000_000.0), "56M");
+        assert_eq!(format_duration(secs(75.0)), "1m15s");
+        assert_eq!(format_duration(secs(3720.0)), "1h02m");
+    }
+}
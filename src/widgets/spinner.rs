@@ -0,0 +1,93 @@
+use crate::animations::{SpinnerStyle, SPINNERS};
+use crate::frame::style::Style;
+use crate::frame::text::{Line, Span};
+use std::time::Instant;
+
+/// A spinner next to a message, for embedding in a line of frame content.
+///
+/// The frame shown is derived from the time since the spinner started, so it advances
+/// at its own interval no matter how often the surrounding content is redrawn.
+#[derive(Clone, Debug)]
+pub struct Spinner {
+    pub spinner: SpinnerStyle,
+    pub message: String,
+    pub style: Style,
+    pub message_style: Style,
+    started: Instant,
+}
+
+impl Default for Spinner {
+    fn default() -> Self {
+        Self::new(SPINNERS[0])
+    }
+}
+
+impl Spinner {
+    pub fn new(spinner: SpinnerStyle) -> Self {
+        Self {
+            spinner,
+            message: String::new(),
+            style: Style::default(),
+            message_style: Style::default(),
+            started: Instant::now(),
+        }
+    }
+
+    pub fn with_message(mut self, message: &str) -> Self {
+        self.message = message.to_owned();
+        self
+    }
+
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_message_style(mut self, message_style: Style) -> Self {
+        self.message_style = message_style;
+        self
+    }
+
+    pub fn set_message(&mut self, message: &str) {
+        self.message = message.to_owned();
+    }
+
+    /// Restart the animation from its first frame.
+    pub fn reset(&mut self) {
+        self.started = Instant::now();
+    }
+
+    /// Index of the frame to show at `now`.
+    pub fn frame_index(&self, now: Instant) -> usize {
+        let interval = self.spinner.interval.as_millis().max(1);
+        let elapsed = now.saturating_duration_since(self.started).as_millis();
+        (elapsed / interval) as usize % self.spinner.frames.len().max(1)
+    }
+
+    /// The spinner glyph to show right now.
+    pub fn glyph(&self) -> &'static str {
+        self.glyph_at(Instant::now())
+    }
+
+    pub fn glyph_at(&self, now: Instant) -> &'static str {
+        self.spinner
+            .frames
+            .get(self.frame_index(now))
+            .copied()
+            .unwrap_or("")
+    }
+
+    /// The spinner and message as a line of frame content.
+    pub fn line(&self) -> Line {
+        self.line_at(Instant::now())
+    }
+
+    pub fn line_at(&self, now: Instant) -> Line {
+        let mut line = Line::new(vec![Span::styled(self.glyph_at(now), self.style)]);
+        if !self.message.is_empty() {
+            line.push(Span::raw(" "));
+            line.push(Span::styled(&self.message, self.message_style));
+        }
+        line
+    }
+}